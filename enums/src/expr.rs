@@ -0,0 +1,173 @@
+use crate::expr::Expr::*;
+use std::fmt;
+
+// Like `Operations`, but an operand can itself be an operation, so the
+// enum refers to itself. A recursive enum would have an infinite size,
+// boxing the operands puts them on the heap behind a pointer of fixed size.
+#[derive(Debug, PartialEq)]
+pub enum Expr {
+    Num(i32),
+    Add(Box<Expr>, Box<Expr>),
+    Sub(Box<Expr>, Box<Expr>),
+    Mul(Box<Expr>, Box<Expr>),
+}
+
+impl Expr {
+    // Evaluate both operands first, then combine them.
+    pub fn eval(&self) -> i32 {
+        match self {
+            Num(n) => *n,
+            Add(left, right) => left.eval() + right.eval(),
+            Sub(left, right) => left.eval() - right.eval(),
+            Mul(left, right) => left.eval() * right.eval(),
+        }
+    }
+}
+
+// Every operation is wrapped in parentheses, so the output reads
+// the same whatever the precedence rules of the reader are.
+impl fmt::Display for Expr {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            Num(n) => write!(f, "{}", n),
+            Add(left, right) => write!(f, "({} + {})", left, right),
+            Sub(left, right) => write!(f, "({} - {})", left, right),
+            Mul(left, right) => write!(f, "({} * {})", left, right),
+        }
+    }
+}
+
+// Reads an `Expr` back from text such as "2 + 3 * (4 - 1)".
+// Each level of precedence is a function calling the next one:
+//
+//   expr   = term (('+' | '-') term)*
+//   term   = factor ('*' factor)*
+//   factor = number | '(' expr ')'
+//
+// so `*` binds tighter than `+` and `-`, and all of them are left associative.
+struct Parser<'a> {
+    input: &'a str,
+    pos: usize,
+}
+
+impl<'a> Parser<'a> {
+    // The next character that isn't whitespace, without consuming it.
+    fn peek(&mut self) -> Option<char> {
+        let rest = &self.input[self.pos..];
+        self.pos += rest.len() - rest.trim_start().len();
+
+        self.input[self.pos..].chars().next()
+    }
+
+    fn bump(&mut self, c: char) {
+        self.pos += c.len_utf8();
+    }
+
+    fn unexpected(&mut self) -> String {
+        match self.peek() {
+            Some(c) => format!("unexpected `{}` at position {}", c, self.pos),
+            None => String::from("unexpected end of input"),
+        }
+    }
+
+    fn expr(&mut self) -> Result<Expr, String> {
+        let mut left = self.term()?;
+
+        while let Some(c @ ('+' | '-')) = self.peek() {
+            self.bump(c);
+            let right = Box::new(self.term()?);
+
+            left = if c == '+' { Add(Box::new(left), right) } else { Sub(Box::new(left), right) };
+        }
+
+        Ok(left)
+    }
+
+    fn term(&mut self) -> Result<Expr, String> {
+        let mut left = self.factor()?;
+
+        while let Some('*') = self.peek() {
+            self.bump('*');
+            left = Mul(Box::new(left), Box::new(self.factor()?));
+        }
+
+        Ok(left)
+    }
+
+    fn factor(&mut self) -> Result<Expr, String> {
+        match self.peek() {
+            Some('(') => {
+                self.bump('(');
+                let inner = self.expr()?;
+
+                match self.peek() {
+                    Some(')') => {
+                        self.bump(')');
+                        Ok(inner)
+                    }
+                    _ => Err(self.unexpected()),
+                }
+            }
+            Some(c) if c.is_ascii_digit() => {
+                let start = self.pos;
+                let digits = self.input[start..]
+                    .find(|c: char| !c.is_ascii_digit())
+                    .unwrap_or(self.input.len() - start);
+                self.pos += digits;
+
+                self.input[start..self.pos].parse()
+                    .map(Num)
+                    .map_err(|err| format!("invalid number at position {}: {}", start, err))
+            }
+            _ => Err(self.unexpected()),
+        }
+    }
+}
+
+pub fn parse_expr(input: &str) -> Result<Expr, String> {
+    let mut parser = Parser { input, pos: 0 };
+    let expr = parser.expr()?;
+
+    // the whole input has to be a single expression.
+    match parser.peek() {
+        None => Ok(expr),
+        Some(_) => Err(parser.unexpected()),
+    }
+}
+
+pub fn display_expr() {
+    // (2 + 3) * 4
+    let expr = Mul(
+        Box::new(Add(Box::new(Num(2)), Box::new(Num(3)))),
+        Box::new(Num(4)),
+    );
+
+    println!("\n{} evaluates to {}", expr, expr.eval());
+    assert_eq!(expr.eval(), 20);
+    assert_eq!(expr.to_string(), "((2 + 3) * 4)");
+    let difference = Sub(Box::new(Num(2)), Box::new(Num(5)));
+    assert_eq!(difference.eval(), -3);
+    assert_eq!(difference.to_string(), "(2 - 5)");
+
+    let parsed = parse_expr("2+3*4");
+    println!("\"2+3*4\" parses to {:?}", parsed);
+    let parsed = parsed.unwrap();
+    assert_eq!(parsed, Add(
+        Box::new(Num(2)),
+        Box::new(Mul(Box::new(Num(3)), Box::new(Num(4)))),
+    ));
+    assert_eq!(parsed.eval(), 14);
+
+    // parentheses, whitespace and left associativity.
+    assert_eq!(parse_expr("(2 + 3) * 4").map(|e| e.eval()), Ok(20));
+    assert_eq!(parse_expr(" 10 - 4 - 3 ").map(|e| e.to_string()), Ok(String::from("((10 - 4) - 3)")));
+    // formatting and parsing back gives the same tree.
+    assert_eq!(parse_expr(&expr.to_string()), Ok(expr));
+
+    println!("\"2++\" fails with {:?}", parse_expr("2++"));
+    assert_eq!(parse_expr("2++"), Err(String::from("unexpected `+` at position 2")));
+    assert_eq!(parse_expr("2+"), Err(String::from("unexpected end of input")));
+    assert_eq!(parse_expr("(1 + 2"), Err(String::from("unexpected end of input")));
+    assert_eq!(parse_expr("1 2"), Err(String::from("unexpected `2` at position 2")));
+    assert!(parse_expr("99999999999").is_err());
+}
@@ -1,26 +1,46 @@
 use crate::linked_list::List::*;
+use std::collections::HashMap;
+use std::hash::Hash;
+use std::ops::Index;
 
-pub enum List {
+// The list is generic over the type `T` of the elements it holds.
+#[derive(Debug, PartialEq)]
+pub enum List<T> {
     // Cons: Tuple struct that wraps an element and a pointer to the next node
-    Cons(u32, Box<List>),
+    Cons(T, Box<List<T>>),
     // Nil: A node that signifies the end of the linked list
     Nil,
 }
 
 // Methods can be attached to an enum
-impl List {
+impl<T> List<T> {
     // Create an empty list
-    pub fn new() -> List {
+    pub fn new() -> List<T> {
         // `Nil` has type `List`
         Nil
     }
 
     // Consume a list, and return the same list with a new element at its front
-    pub fn prepend(self, elem: u32) -> List {
+    pub fn prepend(self, elem: T) -> List<T> {
         // `Cons` also has type List
         Cons(elem, Box::new(self))
     }
 
+    // Consume two lists and return one with the elements of `other`
+    // following the elements of `self`.
+    pub fn append(self, other: List<T>) -> List<T> {
+        match self {
+            Cons(head, tail) => Cons(head, Box::new(tail.append(other))),
+            Nil => other,
+        }
+    }
+
+    // Create a list holding the elements of `items` in the same order.
+    // The last item is prepended first so the first one ends up in front.
+    pub fn from_vec(items: Vec<T>) -> List<T> {
+        items.into_iter().rev().fold(List::new(), List::prepend)
+    }
+
     // Return the length of the list
     pub fn len(&self) -> u32 {
         // `self` has to be matched, because the behavior of this method
@@ -39,10 +59,158 @@ impl List {
         }
     }
 
+    // Return the middle element of the list in a single traversal.
+    // `fast` moves two nodes for every node `slow` moves, so when `fast`
+    // reaches the end `slow` is halfway there. For an even length the
+    // second of the two middle elements is returned.
+    pub fn middle(&self) -> Option<&T> {
+        let mut slow = self;
+        let mut fast = self;
+
+        while let Cons(_, ref next) = *fast {
+            match **next {
+                Cons(_, ref after_next) => {
+                    fast = after_next;
+
+                    if let Cons(_, ref tail) = *slow {
+                        slow = tail;
+                    }
+                },
+                Nil => break,
+            }
+        }
+
+        match *slow {
+            Cons(ref head, _) => Some(head),
+            Nil => None,
+        }
+    }
+
+    // Borrow the elements of the list one by one, front to back.
+    pub fn iter(&self) -> Iter<'_, T> {
+        Iter { node: self }
+    }
+
+    // A new list of clones of the elements matching `pred`, in order.
+    pub fn filter<F: Fn(&T) -> bool>(&self, pred: F) -> List<T> where
+        T: Clone {
+        List::from_vec(self.iter().filter(|elem| pred(elem)).cloned().collect())
+    }
+
+    // A new list where every run of equal neighbouring elements is
+    // collapsed into one, in order. Equal elements that aren't next to
+    // each other are all kept.
+    pub fn dedup_consecutive(&self) -> List<T> where
+        T: PartialEq + Clone {
+        let mut kept: Vec<T> = Vec::new();
+
+        for elem in self.iter() {
+            if kept.last() != Some(elem) {
+                kept.push(elem.clone());
+            }
+        }
+
+        List::from_vec(kept)
+    }
+
+    // Combine the elements of two lists position by position,
+    // stopping at the end of the shorter list.
+    pub fn zip_with<F: Fn(&T, &T) -> T>(&self, other: &List<T>, f: F) -> List<T> {
+        List::from_vec(self.iter().zip(other.iter()).map(|(a, b)| f(a, b)).collect())
+    }
+
+    // How many elements match `pred`, without building a new list.
+    pub fn count_if<F: Fn(&T) -> bool>(&self, pred: F) -> usize {
+        self.iter().filter(|elem| pred(elem)).count()
+    }
+
+    // Reduce the list to a single value, front to back, starting
+    // with `init`. Works the same as `fold` on an iterator.
+    pub fn fold<B, F: Fn(B, &T) -> B>(&self, init: B, f: F) -> B {
+        self.iter().fold(init, f)
+    }
+
+    // Walk `index` nodes down the list and return the element there,
+    // `None` if the list ends first.
+    pub fn nth(&self, index: usize) -> Option<&T> {
+        match *self {
+            Cons(ref head, _) if index == 0 => Some(head),
+            Cons(_, ref tail) => tail.nth(index - 1),
+            Nil => None,
+        }
+    }
+}
+
+// Methods for lists of elements which can be ordered.
+impl<T: Ord> List<T> {
+    // Consume an ascending list and return it with `value` inserted
+    // in its place, so it stays ascending. The list is walked until
+    // the first element that isn't smaller than `value`.
+    pub fn insert_sorted(self, value: T) -> List<T> {
+        match self {
+            Cons(head, tail) if head < value => Cons(head, Box::new(tail.insert_sorted(value))),
+            // `Nil` or an element at least as big: `value` goes in front.
+            list => list.prepend(value),
+        }
+    }
+}
+
+// Methods for a list of lists.
+impl<T> List<List<T>> {
+    // Consume the nested lists and join them, in order, into one list.
+    // Each inner list is followed by the flattened rest of the outer list.
+    pub fn flatten(self) -> List<T> {
+        match self {
+            Cons(inner, rest) => inner.append(rest.flatten()),
+            Nil => Nil,
+        }
+    }
+}
+
+// Aggregates for lists of numbers.
+impl List<i32> {
+    // An empty list sums to 0.
+    pub fn sum(&self) -> i32 {
+        self.iter().sum()
+    }
+
+    // An empty list has no largest element.
+    pub fn max(&self) -> Option<i32> {
+        self.iter().copied().max()
+    }
+
+    // An empty list has no smallest element.
+    pub fn min(&self) -> Option<i32> {
+        self.iter().copied().min()
+    }
+}
+
+// Methods only available on lists of `(key, value)` pairs.
+impl<K: Eq + Hash + Clone> List<(K, i64)> {
+    // For every pair, in order, the total of the values seen so far
+    // for the same key.
+    pub fn running_totals_by_key(&self) -> List<(K, i64)> {
+        let mut totals: HashMap<K, i64> = HashMap::new();
+        let mut running = Vec::new();
+        let mut node = self;
+
+        while let Cons((ref key, value), ref tail) = *node {
+            let total = totals.entry(key.clone()).or_insert(0);
+            *total += value;
+
+            running.push((key.clone(), *total));
+            node = tail;
+        }
+
+        List::from_vec(running)
+    }
+}
+
+impl<T: std::fmt::Display> List<T> {
     // Return representation of the list as a (heap allocated) string
     pub fn stringify(&self) -> String {
         match *self {
-            Cons(head, ref tail) => {
+            Cons(ref head, ref tail) => {
                 // `format!` is similar to `print!`, but returns a heap
                 // allocated string instead of printing to the console
                 format!("{}, {}", head, tail.stringify())
@@ -53,3 +221,196 @@ impl List {
         }
     }
 }
+
+// An iterator over borrowed elements, it holds the node to read next.
+pub struct Iter<'a, T> {
+    node: &'a List<T>,
+}
+
+impl<'a, T> Iterator for Iter<'a, T> {
+    type Item = &'a T;
+
+    fn next(&mut self) -> Option<&'a T> {
+        match *self.node {
+            Cons(ref head, ref tail) => {
+                self.node = tail;
+                Some(head)
+            },
+            Nil => None,
+        }
+    }
+}
+
+// `list[index]` panics when the index is out of range, like a `Vec`.
+impl<T> Index<usize> for List<T> {
+    type Output = T;
+
+    fn index(&self, index: usize) -> &T {
+        match self.nth(index) {
+            Some(elem) => elem,
+            None => panic!("index out of bounds: the len is {} but the index is {}", self.len(), index),
+        }
+    }
+}
+
+// The same `Container` trait as in the generics crate, the two crates
+// don't depend on each other so it is repeated here for `List`.
+pub trait Container {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Container for List<T> {
+    fn len(&self) -> usize {
+        // the inherent `len` takes priority over this trait method.
+        List::len(self) as usize
+    }
+
+    // No need to walk the whole list to know if it is empty.
+    fn is_empty(&self) -> bool {
+        matches!(*self, Nil)
+    }
+}
+
+impl<T> Container for Vec<T> {
+    fn len(&self) -> usize {
+        Vec::len(self)
+    }
+}
+
+pub fn display_middle() {
+    let odd = List::new().prepend(5).prepend(4).prepend(3);
+    let even = List::new().prepend(4).prepend(3).prepend(2).prepend(1);
+    let empty: List<i32> = List::new();
+
+    println!("middle of [{}] is {:?}", odd.stringify(), odd.middle());
+    println!("middle of [{}] is {:?}", even.stringify(), even.middle());
+    println!("middle of [{}] is {:?}", empty.stringify(), empty.middle());
+
+    assert_eq!(odd.middle(), Some(&4));
+    assert_eq!(even.middle(), Some(&3));
+    assert_eq!(empty.middle(), None);
+}
+
+pub fn display_running_totals_by_key() {
+    let sales = List::from_vec(vec![("apple", 3), ("pear", 2), ("apple", 4), ("pear", -1), ("fig", 5)]);
+    let totals = sales.running_totals_by_key();
+
+    println!("running totals by key: {:?}", totals);
+    assert_eq!(totals, List::from_vec(vec![("apple", 3), ("pear", 2), ("apple", 7), ("pear", 1), ("fig", 5)]));
+}
+
+pub fn display_container() {
+    let list = List::from_vec(vec![1, 2, 3]);
+    let empty: List<i32> = List::new();
+    let vec = vec![1, 2];
+
+    println!("list [{}] has {} items", list.stringify(), Container::len(&list));
+
+    assert_eq!(Container::len(&list), 3);
+    assert!(!Container::is_empty(&list));
+    assert_eq!(Container::len(&empty), 0);
+    assert!(Container::is_empty(&empty));
+    assert_eq!(Container::len(&vec), 2);
+    assert!(!Container::is_empty(&vec));
+}
+
+pub fn display_insert_sorted() {
+    let mut list = List::new();
+
+    for value in [3, 1, 2] {
+        list = list.insert_sorted(value);
+    }
+
+    println!("inserting 3, 1, 2 in order gives [{}]", list.stringify());
+    assert_eq!(list, List::from_vec(vec![1, 2, 3]));
+    assert_eq!(List::new().insert_sorted(7), List::from_vec(vec![7]));
+}
+
+pub fn display_nth() {
+    let list = List::from_vec(vec![10, 20, 30]);
+
+    println!("element 1 of [{}] is {:?}", list.stringify(), list.nth(1));
+    assert_eq!(list.nth(1), Some(&20));
+    assert_eq!(list.nth(2), Some(&30));
+    assert_eq!(list.nth(3), None);
+    assert_eq!(list[0], 10);
+}
+
+pub fn display_filter() {
+    let list = List::from_vec(vec![3, 2, 1, 4]);
+    let even = list.filter(|n| n % 2 == 0);
+
+    println!("even elements of [{}] are [{}]", list.stringify(), even.stringify());
+    assert_eq!(even, List::from_vec(vec![2, 4]));
+    assert_eq!(list.filter(|n| *n > 10), List::new());
+}
+
+pub fn display_fold() {
+    let list = List::from_vec(vec![1, 2, 3]);
+
+    let sum = list.fold(0, |acc, n| acc + n);
+    let joined = list.fold(String::new(), |acc, n| {
+        if acc.is_empty() {
+            n.to_string()
+        } else {
+            format!("{},{}", acc, n)
+        }
+    });
+
+    println!("[{}] sums to {} and joins to {:?}", list.stringify(), sum, joined);
+    assert_eq!(sum, 6);
+    assert_eq!(joined, "1,2,3");
+}
+
+pub fn display_dedup_consecutive() {
+    let list = List::from_vec(vec![1, 1, 2, 3, 3, 3]);
+    let deduped = list.dedup_consecutive();
+
+    println!("[{}] without adjacent duplicates is [{}]", list.stringify(), deduped.stringify());
+    assert_eq!(deduped, List::from_vec(vec![1, 2, 3]));
+    assert_eq!(List::<i32>::new().dedup_consecutive(), List::new());
+    assert_eq!(List::from_vec(vec![7]).dedup_consecutive(), List::from_vec(vec![7]));
+}
+
+pub fn display_zip_with() {
+    let list = List::from_vec(vec![1, 2, 3]);
+    let other = List::from_vec(vec![10, 20]);
+    let sums = list.zip_with(&other, |a, b| a + b);
+
+    println!("[{}] + [{}] = [{}]", list.stringify(), other.stringify(), sums.stringify());
+    assert_eq!(sums, List::from_vec(vec![11, 22]));
+}
+
+pub fn display_count_if() {
+    let list = List::from_vec(vec![1, 2, 3, 4, 6]);
+    let even = list.count_if(|n| n % 2 == 0);
+
+    println!("[{}] has {} even elements", list.stringify(), even);
+    assert_eq!(even, 3);
+    assert_eq!(List::<i32>::new().count_if(|n| n % 2 == 0), 0);
+}
+
+pub fn display_aggregates() {
+    let list = List::from_vec(vec![3, 2, 1]);
+    let empty: List<i32> = List::new();
+
+    println!("[{}] has sum {}, max {:?} and min {:?}", list.stringify(), list.sum(), list.max(), list.min());
+    assert_eq!((list.sum(), list.max(), list.min()), (6, Some(3), Some(1)));
+    assert_eq!((empty.sum(), empty.max(), empty.min()), (0, None, None));
+}
+
+pub fn display_flatten() {
+    let nested = List::from_vec(vec![
+        List::from_vec(vec![1, 2]),
+        List::new(),
+        List::from_vec(vec![3, 4, 5]),
+    ]);
+    let flat = nested.flatten();
+
+    println!("flattened list: [{}]", flat.stringify());
+    assert_eq!(flat, List::from_vec(vec![1, 2, 3, 4, 5]));
+}
@@ -1,13 +1,22 @@
 // An attribute to hide warnings for unused code.
 #![allow(dead_code)]
 
+mod expr;
 mod linked_list;
+mod ring_buffer;
+
+use std::cell::RefCell;
+use std::collections::{HashMap, HashSet};
+use std::rc::Rc;
 
 
 // Create an `enum` to classify a web event. Note how both
 // names and type information together specify the variant:
 // `PageLoad != PageUnload` and `KeyPress(char) != Paste(String)`.
 // Each is different and independent.
+// None of the variants hold a float, so events can be `Eq` and
+// `Hash`, which lets them be stored in a `HashSet`.
+#[derive(Debug, PartialEq, Eq, Hash)]
 enum WebEvent {
     // An `enum` may either be `unit-like`,
     PageLoad,
@@ -35,6 +44,282 @@ fn inspect(event: WebEvent) {
     }
 }
 
+impl WebEvent {
+    // The name of the variant, ignoring any data it holds.
+    fn kind(&self) -> &'static str {
+        match self {
+            WebEvent::PageLoad => "page_load",
+            WebEvent::PageUnload => "page_unload",
+            WebEvent::KeyPress(_) => "key_press",
+            WebEvent::Paste(_) => "paste",
+            WebEvent::Click { .. } => "click",
+        }
+    }
+}
+
+// Count how many events of each kind occurred. A kind that
+// never occurred has no entry rather than a count of zero.
+fn summarize(events: &[WebEvent]) -> HashMap<&'static str, usize> {
+    let mut counts = HashMap::new();
+
+    for event in events {
+        *counts.entry(event.kind()).or_insert(0) += 1;
+    }
+
+    counts
+}
+
+fn display_event_summary() {
+    let events = vec![
+        WebEvent::PageLoad,
+        WebEvent::KeyPress('a'),
+        WebEvent::KeyPress('b'),
+        WebEvent::Click { x: 1, y: 2 },
+        WebEvent::KeyPress('c'),
+        WebEvent::PageLoad,
+    ];
+
+    let summary = summarize(&events);
+    println!("\nevent summary: {:?}", summary);
+
+    assert_eq!(summary.len(), 3);
+    assert_eq!(summary["page_load"], 2);
+    assert_eq!(summary["key_press"], 3);
+    assert_eq!(summary["click"], 1);
+    assert_eq!(summary.get("paste"), None);
+    assert_eq!(summary.get("page_unload"), None);
+}
+
+// Drop every event that already occurred earlier, keeping the rest
+// in the order they first occurred. The set only borrows the events,
+// so they are moved into the result rather than cloned.
+fn unique_events(events: Vec<WebEvent>) -> Vec<WebEvent> {
+    let first_seen: Vec<bool> = {
+        let mut seen = HashSet::new();
+        events.iter().map(|event| seen.insert(event)).collect()
+    };
+
+    events.into_iter()
+        .zip(first_seen)
+        .filter_map(|(event, first)| if first { Some(event) } else { None })
+        .collect()
+}
+
+fn display_unique_events() {
+    let events = vec![
+        WebEvent::PageLoad,
+        WebEvent::KeyPress('a'),
+        WebEvent::PageLoad,
+        WebEvent::Click { x: 1, y: 2 },
+        WebEvent::PageLoad,
+        WebEvent::KeyPress('a'),
+        WebEvent::KeyPress('b'),
+    ];
+
+    let unique = unique_events(events);
+    println!("\nunique events: {:?}", unique);
+
+    assert_eq!(unique, vec![
+        WebEvent::PageLoad,
+        WebEvent::KeyPress('a'),
+        WebEvent::Click { x: 1, y: 2 },
+        WebEvent::KeyPress('b'),
+    ]);
+}
+
+// Why a slice of bytes couldn't be read back into a `WebEvent`.
+#[derive(Debug, PartialEq)]
+enum DecodeError {
+    // The buffer ended before the whole event was read.
+    Truncated,
+    // The first byte doesn't name any variant.
+    InvalidTag(u8),
+    // The 4 bytes of a `KeyPress` aren't a valid `char`.
+    InvalidChar(u32),
+    // The bytes of a `Paste` aren't valid UTF-8.
+    InvalidUtf8,
+}
+
+// Tags written as the first byte of an encoded event.
+const PAGE_LOAD: u8 = 0;
+const PAGE_UNLOAD: u8 = 1;
+const KEY_PRESS: u8 = 2;
+const PASTE: u8 = 3;
+const CLICK: u8 = 4;
+
+// Splits `n` bytes off the front of `bytes`.
+fn take(bytes: &[u8], n: usize) -> Result<(&[u8], &[u8]), DecodeError> {
+    if bytes.len() < n {
+        Err(DecodeError::Truncated)
+    } else {
+        Ok(bytes.split_at(n))
+    }
+}
+
+// Splits the first `N` bytes off `bytes` as a fixed size array,
+// ready to be turned into a number.
+fn take_array<const N: usize>(bytes: &[u8]) -> Result<([u8; N], &[u8]), DecodeError> {
+    let (head, rest) = take(bytes, N)?;
+    let mut array = [0; N];
+    array.copy_from_slice(head);
+
+    Ok((array, rest))
+}
+
+// A compact binary form without pulling in a serialization crate:
+// a one byte tag followed by the payload of the variant, numbers are
+// written big-endian.
+//   KeyPress: the char as 4 bytes
+//   Paste: the length of the string as 4 bytes, then its UTF-8 bytes
+//   Click: `x` then `y`, 8 bytes each
+impl WebEvent {
+    fn to_bytes(&self) -> Vec<u8> {
+        match self {
+            WebEvent::PageLoad => vec![PAGE_LOAD],
+            WebEvent::PageUnload => vec![PAGE_UNLOAD],
+            WebEvent::KeyPress(c) => {
+                let mut bytes = vec![KEY_PRESS];
+                bytes.extend_from_slice(&(*c as u32).to_be_bytes());
+                bytes
+            },
+            WebEvent::Paste(s) => {
+                let mut bytes = vec![PASTE];
+                // A length that doesn't fit in 4 bytes can't be written,
+                // cutting it down would make the encoding unreadable.
+                let len = u32::try_from(s.len())
+                    .expect("a pasted text longer than u32::MAX bytes can't be encoded");
+                bytes.extend_from_slice(&len.to_be_bytes());
+                bytes.extend_from_slice(s.as_bytes());
+                bytes
+            },
+            WebEvent::Click { x, y } => {
+                let mut bytes = vec![CLICK];
+                bytes.extend_from_slice(&x.to_be_bytes());
+                bytes.extend_from_slice(&y.to_be_bytes());
+                bytes
+            },
+        }
+    }
+
+    // Decodes the event at the start of `bytes`, returning it with the
+    // number of bytes it took up so any following events can be read.
+    fn from_bytes(bytes: &[u8]) -> Result<(WebEvent, usize), DecodeError> {
+        let (&tag, payload) = bytes.split_first().ok_or(DecodeError::Truncated)?;
+
+        let (event, payload_len) = match tag {
+            PAGE_LOAD => (WebEvent::PageLoad, 0),
+            PAGE_UNLOAD => (WebEvent::PageUnload, 0),
+            KEY_PRESS => {
+                let (code, _) = take_array(payload)?;
+                let code = u32::from_be_bytes(code);
+                let c = char::from_u32(code).ok_or(DecodeError::InvalidChar(code))?;
+                (WebEvent::KeyPress(c), 4)
+            },
+            PASTE => {
+                let (len, rest) = take_array(payload)?;
+                let len = u32::from_be_bytes(len) as usize;
+                let (text, _) = take(rest, len)?;
+                let s = String::from_utf8(text.to_vec()).map_err(|_| DecodeError::InvalidUtf8)?;
+                (WebEvent::Paste(s), 4 + len)
+            },
+            CLICK => {
+                let (x, rest) = take_array(payload)?;
+                let (y, _) = take_array(rest)?;
+                let (x, y) = (i64::from_be_bytes(x), i64::from_be_bytes(y));
+                (WebEvent::Click { x, y }, 16)
+            },
+            _ => return Err(DecodeError::InvalidTag(tag)),
+        };
+
+        Ok((event, 1 + payload_len))
+    }
+}
+
+fn display_event_encoding() {
+    let events = vec![
+        WebEvent::PageLoad,
+        WebEvent::PageUnload,
+        WebEvent::KeyPress('ß'),
+        WebEvent::Paste("my text".to_owned()),
+        WebEvent::Click { x: -20, y: 80 },
+    ];
+
+    for event in events {
+        let bytes = event.to_bytes();
+        println!("{:?} is encoded as {:?}", event, bytes);
+
+        assert_eq!(WebEvent::from_bytes(&bytes), Ok((event, bytes.len())));
+    }
+
+    // A click needs 16 bytes after its tag.
+    let click = WebEvent::Click { x: 1, y: 2 }.to_bytes();
+    assert_eq!(WebEvent::from_bytes(&click[..10]), Err(DecodeError::Truncated));
+    // The paste says it holds 7 bytes but only 3 are there.
+    let paste = WebEvent::Paste("my text".to_owned()).to_bytes();
+    assert_eq!(WebEvent::from_bytes(&paste[..8]), Err(DecodeError::Truncated));
+    assert_eq!(WebEvent::from_bytes(&[]), Err(DecodeError::Truncated));
+    assert_eq!(WebEvent::from_bytes(&[9]), Err(DecodeError::InvalidTag(9)));
+
+    // 0xD800 is a surrogate, which no `char` can hold.
+    let surrogate = [KEY_PRESS, 0x00, 0x00, 0xD8, 0x00];
+    assert_eq!(WebEvent::from_bytes(&surrogate), Err(DecodeError::InvalidChar(0xD800)));
+    // 2 bytes of text, neither of which may appear in UTF-8.
+    let not_utf8 = [PASTE, 0, 0, 0, 2, 0xFF, 0xFE];
+    assert_eq!(WebEvent::from_bytes(&not_utf8), Err(DecodeError::InvalidUtf8));
+}
+
+// Handlers are boxed since every closure has its own type.
+type Handler = Box<dyn Fn(&WebEvent)>;
+
+// Calls every registered handler with each event it is given.
+struct Dispatcher {
+    handlers: Vec<Handler>,
+}
+
+impl Dispatcher {
+    fn new() -> Dispatcher {
+        Dispatcher { handlers: Vec::new() }
+    }
+
+    fn register(&mut self, handler: impl Fn(&WebEvent) + 'static) {
+        self.handlers.push(Box::new(handler));
+    }
+
+    // Handlers are called in the order they were registered.
+    fn dispatch(&self, event: &WebEvent) {
+        for handler in &self.handlers {
+            handler(event);
+        }
+    }
+}
+
+fn display_event_dispatcher() {
+    // The handlers only get `&WebEvent`, whatever they record has to be
+    // shared with them and mutated through a `RefCell`.
+    let seen = Rc::new(RefCell::new(Vec::new()));
+    let clicks = Rc::new(RefCell::new(0));
+
+    let mut dispatcher = Dispatcher::new();
+
+    let kinds = Rc::clone(&seen);
+    dispatcher.register(move |event| kinds.borrow_mut().push(event.kind()));
+
+    let click_count = Rc::clone(&clicks);
+    dispatcher.register(move |event| {
+        if let WebEvent::Click { .. } = event {
+            *click_count.borrow_mut() += 1;
+        }
+    });
+
+    dispatcher.dispatch(&WebEvent::PageLoad);
+    dispatcher.dispatch(&WebEvent::Click { x: 3, y: 4 });
+    dispatcher.dispatch(&WebEvent::KeyPress('x'));
+
+    println!("\ndispatched events: {:?}", seen.borrow());
+    assert_eq!(*seen.borrow(), vec!["page_load", "click", "key_press"]);
+    assert_eq!(*clicks.borrow(), 1);
+}
+
 // type aliases for too long enum names.
 enum VeryVerboseEnumOfThingsToDoWithNumbers {
     Add,
@@ -54,16 +339,81 @@ impl VeryVerboseEnumOfThingsToDoWithNumbers {
     }
 }
 
+// Adding an operation to `Operations` means changing the enum and every
+// `match` on it. With a trait, anyone can add an operation by writing a
+// new type that implements it, and the code running them stays the same.
+trait BinaryOp {
+    fn apply(&self, x: i32, y: i32) -> i32;
+    fn symbol(&self) -> char;
+}
+
+struct AddOp;
+struct SubtractOp;
+struct MultiplyOp;
+
+impl BinaryOp for AddOp {
+    fn apply(&self, x: i32, y: i32) -> i32 { x + y }
+    fn symbol(&self) -> char { '+' }
+}
+
+impl BinaryOp for SubtractOp {
+    fn apply(&self, x: i32, y: i32) -> i32 { x - y }
+    fn symbol(&self) -> char { '-' }
+}
+
+impl BinaryOp for MultiplyOp {
+    fn apply(&self, x: i32, y: i32) -> i32 { x * y }
+    fn symbol(&self) -> char { '*' }
+}
+
+// Run every operation on the same operands, pairing each
+// result with the symbol of the operation that produced it.
+fn run_ops(ops: &[Box<dyn BinaryOp>], x: i32, y: i32) -> Vec<(char, i32)> {
+    ops.iter().map(|op| (op.symbol(), op.apply(x, y))).collect()
+}
+
+fn display_binary_ops() {
+    let ops: Vec<Box<dyn BinaryOp>> = vec![
+        Box::new(AddOp),
+        Box::new(SubtractOp),
+        Box::new(MultiplyOp),
+    ];
+
+    let results = run_ops(&ops, 6, 3);
+    println!("operations on (6, 3): {:?}", results);
+
+    assert_eq!(results, vec![('+', 9), ('-', 3), ('*', 18)]);
+}
+
+#[derive(Debug, PartialEq)]
 enum Status {
     Rich,
     Poor,
 }
 
+impl Status {
+    // Which kind of shop the status goes with.
+    fn price_class(&self) -> &'static str {
+        match self {
+            Status::Rich => "premium",
+            Status::Poor => "budget",
+        }
+    }
+}
+
 enum Work {
     Civilian,
     Soldier,
 }
 
+// The status a kind of work usually goes with.
+fn typical_status(work: &Work) -> Status {
+    match work {
+        Work::Civilian => Status::Rich,
+        Work::Soldier => Status::Poor,
+    }
+}
+
 // Explicitly `use` each name so they are available without
 // manual scoping like typing use std::fmt; at the top
 // It can be used for inline import of a crate.
@@ -88,9 +438,19 @@ fn display_using_use() {
         Civilian => println!("civilian work"),
         Soldier => println!("soldier work"),
     }
+
+    println!("{:?} shops {}", Rich, Rich.price_class());
+    assert_eq!(Rich, Status::Rich);
+    assert_ne!(Rich, Poor);
+    assert_eq!(Rich.price_class(), "premium");
+    assert_eq!(Poor.price_class(), "budget");
+
+    assert_eq!(typical_status(&Civilian), Rich);
+    assert_eq!(typical_status(&Soldier), Poor);
 }
 
 // enum with implicit discriminator (starts at 0), like iota in Go
+#[derive(Debug, Clone, Copy, PartialEq)]
 enum DaysOfTheWeek {
     Sunday,
     Monday,
@@ -101,6 +461,19 @@ enum DaysOfTheWeek {
     Saturday,
 }
 
+impl DaysOfTheWeek {
+    // Every day, in the order of their discriminants.
+    fn all() -> [DaysOfTheWeek; 7] {
+        use DaysOfTheWeek::*;
+
+        [Sunday, Monday, Tuesday, Wednesday, Thursday, Friday, Saturday]
+    }
+
+    fn is_weekend(&self) -> bool {
+        matches!(self, DaysOfTheWeek::Saturday | DaysOfTheWeek::Sunday)
+    }
+}
+
 fn display_days_of_the_week() {
     // `enums` can be cast as integers.
     println!("\ndays of the week:\n");
@@ -111,6 +484,17 @@ fn display_days_of_the_week() {
     println!("thursday is {}", DaysOfTheWeek::Thursday as u32);
     println!("friday is {}", DaysOfTheWeek::Friday as u32);
     println!("saturday is {}", DaysOfTheWeek::Saturday as u32);
+
+    let days = DaysOfTheWeek::all();
+    let weekend: Vec<DaysOfTheWeek> = days.iter().copied().filter(DaysOfTheWeek::is_weekend).collect();
+    println!("the weekend is {:?}", weekend);
+
+    assert_eq!(days[0], DaysOfTheWeek::Sunday);
+    assert_eq!(days[6], DaysOfTheWeek::Saturday);
+    assert_eq!(weekend, vec![DaysOfTheWeek::Sunday, DaysOfTheWeek::Saturday]);
+    assert!(!DaysOfTheWeek::Wednesday.is_weekend());
+    // `all` follows the discriminants.
+    assert!(days.iter().enumerate().all(|(i, &day)| day as usize == i));
 }
 
 enum Color {
@@ -139,6 +523,11 @@ fn main() {
     inspect(load);
     inspect(unload);
 
+    display_event_summary();
+    display_unique_events();
+    display_event_encoding();
+    display_event_dispatcher();
+
     // We can refer to each variant via its alias, not its long and inconvenient
     // name.
     let add = Operations::Add;
@@ -147,6 +536,8 @@ fn main() {
     println!("add - {}", add.run(64, 30));
     println!("substract - {}", subtract.run(64, 30));
 
+    display_binary_ops();
+
     display_using_use();
     display_days_of_the_week();
     display_colour();
@@ -164,4 +555,21 @@ fn main() {
     println!("\nlinked list section -");
     println!("linked list has length: {}", list.len());
     println!("{}", list.stringify());
+
+    linked_list::display_middle();
+    linked_list::display_running_totals_by_key();
+    linked_list::display_container();
+    linked_list::display_insert_sorted();
+    linked_list::display_nth();
+    linked_list::display_filter();
+    linked_list::display_fold();
+    linked_list::display_dedup_consecutive();
+    linked_list::display_zip_with();
+    linked_list::display_count_if();
+    linked_list::display_aggregates();
+    linked_list::display_flatten();
+
+    ring_buffer::display_ring_buffer();
+
+    expr::display_expr();
 }
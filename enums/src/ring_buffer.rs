@@ -0,0 +1,89 @@
+// A ring buffer holds at most `capacity` elements. Like the nodes of
+// the linked list each slot either holds an element or is empty, but
+// the slots are fixed at construction and reused in a circle: once the
+// buffer is full, pushing overwrites the oldest element.
+pub struct RingBuffer<T> {
+    slots: Vec<Option<T>>,
+    // Index of the oldest element, the front of the buffer.
+    head: usize,
+    len: usize,
+}
+
+impl<T> RingBuffer<T> {
+    pub fn new(capacity: usize) -> RingBuffer<T> {
+        assert!(capacity > 0, "a ring buffer needs room for at least one element");
+
+        RingBuffer {
+            slots: (0..capacity).map(|_| None).collect(),
+            head: 0,
+            len: 0,
+        }
+    }
+
+    pub fn capacity(&self) -> usize {
+        self.slots.len()
+    }
+
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    // Add `elem` at the back, returning the oldest element if
+    // it had to be overwritten to make room.
+    pub fn push(&mut self, elem: T) -> Option<T> {
+        let tail = (self.head + self.len) % self.capacity();
+        let overwritten = self.slots[tail].replace(elem);
+
+        if self.len == self.capacity() {
+            // the slot after the overwritten one is now the oldest.
+            self.head = (self.head + 1) % self.capacity();
+        } else {
+            self.len += 1;
+        }
+
+        overwritten
+    }
+
+    // Remove and return the oldest element.
+    pub fn pop(&mut self) -> Option<T> {
+        if self.is_empty() {
+            return None;
+        }
+
+        let elem = self.slots[self.head].take();
+        self.head = (self.head + 1) % self.capacity();
+        self.len -= 1;
+
+        elem
+    }
+}
+
+pub fn display_ring_buffer() {
+    let mut buffer = RingBuffer::new(3);
+
+    buffer.push(1);
+    buffer.push(2);
+    buffer.push(3);
+    assert_eq!(buffer.len(), 3);
+
+    // full: pushing wraps around and overwrites the oldest element.
+    let overwritten = buffer.push(4);
+    println!("\nring buffer of capacity {} overwrote {:?}", buffer.capacity(), overwritten);
+    assert_eq!(overwritten, Some(1));
+    assert_eq!(buffer.push(5), Some(2));
+    assert_eq!(buffer.len(), 3);
+
+    assert_eq!(buffer.pop(), Some(3));
+    assert_eq!(buffer.pop(), Some(4));
+
+    // room again, nothing is overwritten.
+    assert_eq!(buffer.push(6), None);
+    assert_eq!(buffer.pop(), Some(5));
+    assert_eq!(buffer.pop(), Some(6));
+    assert_eq!(buffer.pop(), None);
+    assert!(buffer.is_empty());
+}
@@ -21,6 +21,18 @@ enum Foo {
     Qux(u32)
 }
 
+// An `if let ... else` chain can classify every variant,
+// the final `else` is left with `Baz`.
+fn describe_foo(f: &Foo) -> String {
+    if let Foo::Bar = f {
+        String::from("bar")
+    } else if let Foo::Qux(value) = f {
+        format!("qux({})", value)
+    } else {
+        String::from("baz")
+    }
+}
+
 pub fn display_if_let() {
     // All have type `Option<i32>`
     let number = Some(7);
@@ -81,6 +93,10 @@ pub fn display_if_let() {
         println!("c is one hundred");
     }
 
+    assert_eq!(describe_foo(&a), "bar");
+    assert_eq!(describe_foo(&b), "baz");
+    assert_eq!(describe_foo(&c), "qux(100)");
+
     // This enum purposely neither implements nor derives PartialEq.
     // That is why comparing Colour::Black == colour fails below.
     enum Colour { Black }
@@ -137,4 +153,18 @@ pub fn display_while_let() {
         // ^ Less rightward drift and doesn't require
         // explicitly handling the failing case.
     }
+
+    assert_eq!(drain(vec![1, 2, 3]), vec![3, 2, 1]);
+}
+
+// `while let` on a real collection: `pop` gives `Some` item until
+// the stack is empty, items come off the top so the order is reversed.
+fn drain<T>(mut stack: Vec<T>) -> Vec<T> {
+    let mut drained = Vec::with_capacity(stack.len());
+
+    while let Some(x) = stack.pop() {
+        drained.push(x);
+    }
+
+    drained
 }
\ No newline at end of file
@@ -51,6 +51,38 @@ pub fn display_labelled_loops() {
     println!("Exited the outer loop");
 }
 
+// A labelled loop doing real work: stop scanning every row as
+// soon as the target is found and hand back where it was.
+fn find_in_grid(grid: &[Vec<i32>], target: i32) -> Option<(usize, usize)> {
+    let mut position = None;
+
+    'rows: for (row, cells) in grid.iter().enumerate() {
+        for (column, &cell) in cells.iter().enumerate() {
+            if cell == target {
+                position = Some((row, column));
+
+                // `break` alone would only move on to the next row.
+                break 'rows;
+            }
+        }
+    }
+
+    position
+}
+
+pub fn display_grid_search() {
+    let grid = vec![
+        vec![1, 2, 3],
+        vec![4, 5, 6],
+        vec![7, 8, 5],
+    ];
+
+    println!("5 is first found at {:?}", find_in_grid(&grid, 5));
+    assert_eq!(find_in_grid(&grid, 5), Some((1, 1)));
+    assert_eq!(find_in_grid(&grid, 10), None);
+    assert_eq!(find_in_grid(&[], 5), None);
+}
+
 pub fn display_loop_with_return_value() {
     let mut counter = 0;
 
@@ -65,36 +97,109 @@ pub fn display_loop_with_return_value() {
     assert_eq!(result, 20);
 }
 
+// A loop keeping state between iterations: retry `f` with the attempt
+// number (starting at 1) until it succeeds, giving up after `max` attempts.
+// The backoff doubles after every failure, a real retry would sleep that long.
+fn attempt_until(max: u32, mut f: impl FnMut(u32) -> bool) -> Option<u32> {
+    let mut attempt = 0;
+    let mut backoff_ms = 100;
+
+    loop {
+        attempt += 1;
+
+        if attempt > max {
+            break None;
+        }
+
+        if f(attempt) {
+            break Some(attempt);
+        }
+
+        println!("attempt {} failed, backing off for {}ms", attempt, backoff_ms);
+        backoff_ms *= 2;
+    }
+}
+
+pub fn display_retry() {
+    let mut calls = 0;
+    let succeeded = attempt_until(5, |attempt| {
+        calls += 1;
+        attempt == 3
+    });
+
+    println!("succeeded on attempt {:?} after {} calls", succeeded, calls);
+    assert_eq!(succeeded, Some(3));
+    assert_eq!(calls, 3);
+    assert_eq!(attempt_until(2, |_| false), None);
+}
+
+// The Collatz sequence: halve even numbers, triple odd numbers and add one,
+// until reaching 1. `successors` keeps producing the next value from the
+// last one until the closure returns `None`, so the loop is driven by
+// the iterator. There's no sequence for 0, it would loop forever.
+// A large odd number can make `3 * n + 1` overflow a `u64`, the sequence
+// then ends at the last number that could be computed instead of panicking.
+fn collatz(start: u64) -> Vec<u64> {
+    if start == 0 {
+        return Vec::new();
+    }
+
+    std::iter::successors(Some(start), |&n| {
+        if n == 1 {
+            None
+        } else if n % 2 == 0 {
+            Some(n / 2)
+        } else {
+            n.checked_mul(3).and_then(|m| m.checked_add(1))
+        }
+    }).collect()
+}
+
+fn collatz_len(start: u64) -> usize {
+    collatz(start).len()
+}
+
+pub fn display_collatz() {
+    println!("collatz sequence of 6: {:?}", collatz(6));
+
+    assert_eq!(collatz(6), vec![6, 3, 10, 5, 16, 8, 4, 2, 1]);
+    assert_eq!(collatz_len(1), 1);
+    assert_eq!(collatz(0), vec![]);
+    assert_eq!(collatz(u64::MAX), vec![u64::MAX]);
+}
+
+// FizzBuzz as a value rather than a print, so it can be reused.
+fn fizzbuzz(n: u32) -> String {
+    if n % 15 == 0 {
+        String::from("fizzbuzz")
+    } else if n % 3 == 0 {
+        String::from("fizz")
+    } else if n % 5 == 0 {
+        String::from("buzz")
+    } else {
+        n.to_string()
+    }
+}
+
 // The for in construct can be used to iterate through an Iterator.
 // One of the easiest ways to create an iterator is to use 
 // the range notation a..b.
 pub fn display_for_loop() {
     // `n` will take the values: 1, 2, ..., 100 in each iteration
     for n in 1..101 {
-        if n % 15 == 0 {
-            println!("fizzbuzz");
-        } else if n % 3 == 0 {
-            println!("fizz");
-        } else if n % 5 == 0 {
-            println!("buzz");
-        } else {
-            println!("{}", n);
-        }
+        println!("{}", fizzbuzz(n));
     }
 
     // `n` will take the values: 1, 2, ..., 100 in each iteration
     for n in 1..=100 {
-        if n % 15 == 0 {
-            println!("fizzbuzz");
-        } else if n % 3 == 0 {
-            println!("fizz");
-        } else if n % 5 == 0 {
-            println!("buzz");
-        } else {
-            println!("{}", n);
-        }
+        println!("{}", fizzbuzz(n));
     }
 
+    assert_eq!(fizzbuzz(3), "fizz");
+    assert_eq!(fizzbuzz(5), "buzz");
+    assert_eq!(fizzbuzz(15), "fizzbuzz");
+    assert_eq!(fizzbuzz(7), "7");
+
     // by default the for loop will apply the into_iter
     // function to the collection. This means that the
     // collection must implement the Iterator trait
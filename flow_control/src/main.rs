@@ -9,14 +9,22 @@ fn main() {
 
     loops::display_for_loop();
     loops::display_labelled_loops();
+    loops::display_grid_search();
     loops::display_loop();
     loops::display_loop_with_return_value();
+    loops::display_collatz();
+    loops::display_retry();
 
     pattern_matching::display_regular_match(16);
+    pattern_matching::display_roman_numerals();
+    pattern_matching::display_calculator();
     pattern_matching::display_tuple_destructuring_match((0, 20, 10));
 
     let colour = pattern_matching::Color::Red;
     pattern_matching::display_match_with_enums(colour);
+    pattern_matching::display_hue_rotation();
+    pattern_matching::display_rgb_parsing();
+    pattern_matching::display_traffic_light();
     pattern_matching::display_pointer_ref_match();
     pattern_matching::display_struct_match();
     pattern_matching::display_match_guard((4, 4), 30);
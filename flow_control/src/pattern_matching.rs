@@ -1,3 +1,6 @@
+use std::fmt;
+use std::str::FromStr;
+
 // match is used like a switch statement,
 // The first matching arm is evaluated and all
 // possible values must be covered.
@@ -25,6 +28,80 @@ pub fn display_regular_match(number: i32) {
     println!("{} -> {}", boolean, binary);
 }
 
+// Roman numerals only go from 1 to 3999.
+// Each range picks the largest numeral that still fits in what is
+// left of the number, that numeral is written and taken away.
+fn to_roman(n: u32) -> Option<String> {
+    match n {
+        1..=3999 => (),
+        _ => return None,
+    }
+
+    let mut rest = n;
+    let mut roman = String::new();
+
+    while rest > 0 {
+        let (numeral, value) = match rest {
+            1000..=3999 => ("M", 1000),
+            900..=999 => ("CM", 900),
+            500..=899 => ("D", 500),
+            400..=499 => ("CD", 400),
+            100..=399 => ("C", 100),
+            90..=99 => ("XC", 90),
+            50..=89 => ("L", 50),
+            40..=49 => ("XL", 40),
+            10..=39 => ("X", 10),
+            9 => ("IX", 9),
+            5..=8 => ("V", 5),
+            4 => ("IV", 4),
+            _ => ("I", 1),
+        };
+
+        roman.push_str(numeral);
+        rest -= value;
+    }
+
+    Some(roman)
+}
+
+pub fn display_roman_numerals() {
+    println!("1994 in roman numerals is {:?}", to_roman(1994));
+
+    assert_eq!(to_roman(4), Some(String::from("IV")));
+    assert_eq!(to_roman(1994), Some(String::from("MCMXCIV")));
+    assert_eq!(to_roman(0), None);
+    assert_eq!(to_roman(4000), None);
+}
+
+// A tiny calculator: the operator picks the arm.
+// Match guards catch a zero divisor before it can panic.
+fn eval(a: i32, op: char, b: i32) -> Result<i32, String> {
+    let result = match op {
+        '+' => a.checked_add(b),
+        '-' => a.checked_sub(b),
+        '*' => a.checked_mul(b),
+        '/' | '%' if b == 0 => return Err(format!("cannot divide {} by zero", a)),
+        '/' => a.checked_div(b),
+        '%' => a.checked_rem(b),
+        _ => return Err(format!("unknown operator `{}`", op)),
+    };
+
+    result.ok_or_else(|| format!("{} {} {} overflows", a, op, b))
+}
+
+pub fn display_calculator() {
+    println!("7 / 2 = {:?}", eval(7, '/', 2));
+
+    assert_eq!(eval(7, '+', 2), Ok(9));
+    assert_eq!(eval(7, '-', 2), Ok(5));
+    assert_eq!(eval(7, '*', 2), Ok(14));
+    assert_eq!(eval(7, '/', 2), Ok(3));
+    assert_eq!(eval(7, '%', 2), Ok(1));
+    assert_eq!(eval(7, '^', 2), Err(String::from("unknown operator `^`")));
+    assert_eq!(eval(7, '/', 0), Err(String::from("cannot divide 7 by zero")));
+    assert_eq!(eval(7, '%', 0), Err(String::from("cannot divide 7 by zero")));
+}
+
 pub fn display_tuple_destructuring_match(dimensions: (i32, i32, i32)) {
     println!("Tell me about dimensions: {:?}", dimensions);
     match dimensions {
@@ -40,6 +117,7 @@ pub fn display_tuple_destructuring_match(dimensions: (i32, i32, i32)) {
 // `allow` required to silence warnings because only
 // one variant is used.
 #[allow(dead_code)]
+#[derive(Debug, PartialEq)]
 pub enum Color {
     // These 3 are specified solely by their name.
     Red,
@@ -53,6 +131,112 @@ pub enum Color {
     CMYK(u32, u32, u32, u32),
 }
 
+impl Color {
+    // Turn the hue of an HSV color around the color wheel by `degrees`,
+    // wrapping around at 360 in both directions.
+    // Other color models are returned unchanged.
+    pub fn rotate_hue(self, degrees: i32) -> Color {
+        match self {
+            Color::HSV(h, s, v) => {
+                // Both are reduced first and summed as `i64`, so neither a
+                // large hue nor a large turn can overflow or wrap.
+                let hue = ((h % 360) as i64 + degrees as i64).rem_euclid(360) as u32;
+                Color::HSV(hue, s, v)
+            },
+            color => color,
+        }
+    }
+}
+
+// A color as three bytes, written as a hex string like "#00ff00".
+#[derive(Debug, PartialEq, Clone, Copy)]
+pub struct Rgb {
+    r: u8,
+    g: u8,
+    b: u8,
+}
+
+impl FromStr for Rgb {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let hex = match s.strip_prefix('#') {
+            Some(hex) if hex.len() == 6 && hex.chars().all(|c| c.is_ascii_hexdigit()) => hex,
+            _ => return Err(format!("`{}` is not of the form #RRGGBB", s)),
+        };
+
+        // Two hex digits always fit in a `u8`.
+        let channel = |i: usize| u8::from_str_radix(&hex[i..i + 2], 16).unwrap();
+
+        Ok(Rgb { r: channel(0), g: channel(2), b: channel(4) })
+    }
+}
+
+impl fmt::Display for Rgb {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "#{:02x}{:02x}{:02x}", self.r, self.g, self.b)
+    }
+}
+
+impl From<Rgb> for Color {
+    fn from(rgb: Rgb) -> Self {
+        Color::RGB(rgb.r as u32, rgb.g as u32, rgb.b as u32)
+    }
+}
+
+// Only the named colors and `RGB` values with every channel
+// up to 255 can be turned into an `Rgb`.
+impl TryFrom<&Color> for Rgb {
+    type Error = String;
+
+    fn try_from(color: &Color) -> Result<Self, Self::Error> {
+        match *color {
+            Color::Red => Ok(Rgb { r: 255, g: 0, b: 0 }),
+            Color::Green => Ok(Rgb { r: 0, g: 255, b: 0 }),
+            Color::Blue => Ok(Rgb { r: 0, g: 0, b: 255 }),
+            Color::RGB(r, g, b) => match (u8::try_from(r), u8::try_from(g), u8::try_from(b)) {
+                (Ok(r), Ok(g), Ok(b)) => Ok(Rgb { r, g, b }),
+                _ => Err(format!("RGB({}, {}, {}) has a channel above 255", r, g, b)),
+            },
+            ref other => Err(format!("{:?} is not an RGB color", other)),
+        }
+    }
+}
+
+pub fn display_rgb_parsing() {
+    let green: Rgb = "#00ff00".parse().unwrap();
+    println!("#00ff00 parses to {:?} and displays as {}", green, green);
+
+    assert_eq!(green, Rgb { r: 0, g: 255, b: 0 });
+    assert_eq!(green.to_string(), "#00ff00");
+    assert!("00ff00".parse::<Rgb>().is_err());
+    assert!("#00gg00".parse::<Rgb>().is_err());
+    assert!("#+f00ff".parse::<Rgb>().is_err());
+
+    assert_eq!(Color::from(green), Color::RGB(0, 255, 0));
+    assert_eq!(Rgb::try_from(&Color::RGB(0, 255, 0)), Ok(green));
+    assert_eq!(Rgb::try_from(&Color::Green), Ok(green));
+    assert!(Rgb::try_from(&Color::RGB(0, 256, 0)).is_err());
+    assert!(Rgb::try_from(&Color::HSV(0, 0, 0)).is_err());
+}
+
+pub fn display_hue_rotation() {
+    let rotated = Color::HSV(300, 50, 50).rotate_hue(120);
+    println!("HSV(300, 50, 50) rotated by 120 degrees is {:?}", rotated);
+    assert_eq!(rotated, Color::HSV(60, 50, 50));
+
+    let rotated = Color::HSV(10, 50, 50).rotate_hue(-30);
+    println!("HSV(10, 50, 50) rotated by -30 degrees is {:?}", rotated);
+    assert_eq!(rotated, Color::HSV(340, 50, 50));
+
+    assert_eq!(Color::Red.rotate_hue(90), Color::Red);
+
+    // i32::MAX is 127 past a whole number of turns.
+    assert_eq!(Color::HSV(300, 50, 50).rotate_hue(i32::MAX), Color::HSV(67, 50, 50));
+    assert_eq!(Color::HSV(300, 50, 50).rotate_hue(i32::MIN), Color::HSV(172, 50, 50));
+    assert_eq!(Color::HSV(u32::MAX, 50, 50).rotate_hue(0), Color::HSV(255, 50, 50));
+}
+
 pub fn display_match_with_enums(color: Color) {
     println!("What color is it?");
     // An `enum` can be destructured using a `match`.
@@ -75,6 +259,58 @@ pub fn display_match_with_enums(color: Color) {
     }
 }
 
+// A state machine: every light knows which one comes after it
+// and how long it stays on.
+#[derive(Debug, PartialEq, Clone, Copy)]
+enum TrafficLight {
+    Red,
+    Yellow,
+    Green,
+}
+
+impl TrafficLight {
+    fn next(self) -> TrafficLight {
+        match self {
+            TrafficLight::Green => TrafficLight::Yellow,
+            TrafficLight::Yellow => TrafficLight::Red,
+            TrafficLight::Red => TrafficLight::Green,
+        }
+    }
+
+    fn duration_secs(&self) -> u32 {
+        match self {
+            TrafficLight::Red => 30,
+            TrafficLight::Yellow => 5,
+            TrafficLight::Green => 25,
+        }
+    }
+}
+
+pub fn display_traffic_light() {
+    let mut light = TrafficLight::Green;
+    let mut cycle = vec![light];
+    let mut total = 0;
+
+    for _ in 0..3 {
+        total += light.duration_secs();
+        light = light.next();
+        cycle.push(light);
+    }
+
+    println!("traffic light cycle: {:?} takes {} seconds", cycle, total);
+    assert_eq!(cycle, vec![
+        TrafficLight::Green,
+        TrafficLight::Yellow,
+        TrafficLight::Red,
+        TrafficLight::Green,
+    ]);
+
+    assert_eq!(TrafficLight::Red.duration_secs(), 30);
+    assert_eq!(TrafficLight::Yellow.duration_secs(), 5);
+    assert_eq!(TrafficLight::Green.duration_secs(), 25);
+    assert_eq!(total, 60);
+}
+
 pub fn display_pointer_ref_match() {
     // Assign a reference of type `i32`. The `&` signifies there
     // is a reference being assigned.
@@ -164,6 +400,16 @@ pub fn display_match_guard(pair: (i32, i32), number: u8) {
     }
 }
 
+// The age group of `age`, nothing is printed so it can be reused.
+fn categorize_age(age: u32) -> &'static str {
+    match age {
+        0 => "infant",
+        1 ..= 12 => "child",
+        13 ..= 19 => "teen",
+        _ => "adult",
+    }
+}
+
 // match provides the @ sigil for binding values to names.
 pub fn display_match_with_binding(age: u32, number_option: Option<u32>) {
     println!("Tell me your age:");
@@ -173,12 +419,18 @@ pub fn display_match_with_binding(age: u32, number_option: Option<u32>) {
         // Could `match` 1 ..= 12 directly but then what age
         // would the child be? Instead, bind to `n` for the
         // sequence of 1 ..= 12. Now the age can be reported.
-        n @ 1 ..= 12 => println!("I'm a child of age {:?}", n),
-        n @ 13 ..= 19 => println!("I'm a teen of age {:?}", n),
-        // Nothing bound. Return the result.
+        n @ 1 ..= 12 => println!("I'm a {} of age {:?}", categorize_age(n), n),
+        n @ 13 ..= 19 => println!("I'm a {} of age {:?}", categorize_age(n), n),
+        // Any other age, bound to `n` as well.
         n => println!("I'm an old person of age {:?}", n),
     }
 
+    assert_eq!(categorize_age(0), "infant");
+    assert_eq!(categorize_age(12), "child");
+    assert_eq!(categorize_age(13), "teen");
+    assert_eq!(categorize_age(19), "teen");
+    assert_eq!(categorize_age(20), "adult");
+
     match number_option {
         // Got `Some` variant, match if its value, bound to `n`,
         // is equal to 42.
@@ -143,6 +143,18 @@ fn apply_append_to_array<F>(mut f: F, arr: Vec<&str>) where
     f(arr);
 }
 
+// A function which feeds the output of the closure back into it
+// `n` times. `f` is only borrowed by each call, so `Fn` is enough.
+fn apply_n_times<T, F: Fn(T) -> T>(init: T, n: usize, f: F) -> T {
+    let mut value = init;
+
+    for _ in 0..n {
+        value = f(value);
+    }
+
+    value
+}
+
 pub fn display_closure_as_input_parameters() {
     // A non-copy type.
     let greeting = "hello";
@@ -188,6 +200,12 @@ pub fn display_closure_as_input_parameters() {
 
     let mut arr = vec!["foot"];
     apply_append_to_array(append_to_arr, arr);
+
+    let doubled = apply_n_times(1, 3, |x| x * 2);
+    println!("1 doubled 3 times -> {}", doubled);
+    assert_eq!(doubled, 8);
+    assert_eq!(apply_n_times(1, 0, |x| x * 2), 1);
+    assert_eq!(apply_n_times(String::from("a"), 2, |s| s + "b"), "abb");
 }
 
 /*
@@ -225,6 +243,30 @@ fn create_fnonce() -> impl FnOnce() {
     move || println!("This is a: {}", text)
 }
 
+// The returned closure keeps the argument it was last called
+// with, so it has to mutate its state: it is an `FnMut`.
+// Each call hands back the previous argument, the first call
+// has nothing stored yet and returns its own argument.
+fn cache_last<T: Clone + 'static>() -> impl FnMut(T) -> T {
+    let mut last: Option<T> = None;
+
+    move |current| {
+        let previous = last.replace(current.clone());
+        previous.unwrap_or(current)
+    }
+}
+
+// The running total is moved into the closure, which owns it from then
+// on: every call adds to it and hands back the new total.
+fn make_accumulator(start: i32) -> impl FnMut(i32) -> i32 {
+    let mut total = start;
+
+    move |amount| {
+        total += amount;
+        total
+    }
+}
+
 pub fn display_closure_as_output() {
     let fn_plain = create_fn();
     let mut fn_mut = create_fnmut();
@@ -233,4 +275,53 @@ pub fn display_closure_as_output() {
     fn_plain();
     fn_mut();
     fn_once();
-}
\ No newline at end of file
+
+    let mut cached = cache_last();
+    let first = cached(1);
+    let second = cached(2);
+
+    println!("cache_last returned {} then {}", first, second);
+    assert_eq!(first, 1);
+    assert_eq!(second, 1);
+    assert_eq!(cached(3), 2);
+
+    let mut accumulate = make_accumulator(0);
+    let (first, second) = (accumulate(10), accumulate(5));
+
+    println!("accumulated {} then {}", first, second);
+    assert_eq!((first, second), (10, 15));
+    assert_eq!(make_accumulator(100)(1), 101);
+}
+
+/*
+    Closures in iterator adapters.
+
+    Iterator adapters are lazy, they only do work when the next item is
+    asked for. A `move` closure can carry its own state along with the
+    adapter, so items are looked at one by one without collecting
+    anything internally.
+*/
+
+// Yields the items of `iter` but skips any item equal to the one
+// yielded right before it.
+fn dedup_consecutive<T: PartialEq + Clone>(iter: impl Iterator<Item = T>) -> impl Iterator<Item = T> {
+    // the closure owns `last` so it lives as long as the adapter.
+    let mut last: Option<T> = None;
+
+    iter.filter(move |item| {
+        if last.as_ref() == Some(item) {
+            false
+        } else {
+            last = Some(item.clone());
+            true
+        }
+    })
+}
+
+pub fn display_closure_in_iterator_adapter() {
+    let numbers = vec![1, 1, 2, 2, 3, 1];
+    let deduped: Vec<i32> = dedup_consecutive(numbers.clone().into_iter()).collect();
+
+    println!("{:?} without consecutive duplicates -> {:?}", numbers, deduped);
+    assert_eq!(deduped, vec![1, 2, 3, 1]);
+}
@@ -6,6 +6,9 @@
 // are defined on a type generally, while methods are associated functions
 // that are called on a particular instance of a type.
 
+use std::mem;
+use std::ops::Mul;
+
 struct Point {
     x: f64,
     y: f64,
@@ -46,6 +49,19 @@ impl Rectangle {
         ((x1 - x2) * (y1 - y2)).abs()
     }
 
+    // Huge coordinates can make the area overflow to infinity, and an
+    // infinite or NaN coordinate spreads to the result. Rather than
+    // handing such a value back, report it as an error.
+    fn checked_area(&self) -> Result<f64, String> {
+        let area = self.area();
+
+        if area.is_finite() {
+            Ok(area)
+        } else {
+            Err(format!("area is not a finite number: {}", area))
+        }
+    }
+
     fn perimeter(self: &Self) -> f64 {
         let Point { x: x1, y: y1 } = self.p1;
         let Point { x: x2, y: y2 } = self.p2;
@@ -64,7 +80,24 @@ impl Rectangle {
     }
 }
 
+// Operator overloading: `rectangle * factor` scales the rectangle
+// about the origin by multiplying every coordinate by `factor`.
+// `mul` takes `self`, so the original rectangle is consumed.
+impl Mul<f64> for Rectangle {
+    type Output = Rectangle;
+
+    fn mul(self, factor: f64) -> Rectangle {
+        Rectangle {
+            p1: Point::new(self.p1.x * factor, self.p1.y * factor),
+            p2: Point::new(self.p2.x * factor, self.p2.y * factor),
+        }
+    }
+}
+
 // `Pair` owns resources: two heap allocated integers
+// Cloning a `Pair` allocates two new boxes with the same values,
+// and two pairs are equal when their boxed values are equal.
+#[derive(Debug, Clone, PartialEq)]
 struct Pair(Box<i32>, Box<i32>);
 
 impl Pair {
@@ -77,6 +110,39 @@ impl Pair {
 
         // `first` and `second` go out of scope and get freed
     }
+
+    // This method only borrows the boxed values
+    fn sum(&self) -> i32 {
+        *self.0 + *self.1
+    }
+
+    // This method consumes the pair and moves its boxes, in reverse order,
+    // into a new pair. No new allocation is made.
+    fn swapped(self) -> Pair {
+        let Pair(first, second) = self;
+
+        Pair(second, first)
+    }
+}
+
+// `Pair` for any type: both values are still on the heap.
+struct Pair2<T>(Box<T>, Box<T>);
+
+impl<T> Pair2<T> {
+    // Consumes the pair and moves both values out of their boxes,
+    // the boxes themselves are freed.
+    fn into_inner(self) -> (T, T) {
+        let Pair2(first, second) = self;
+
+        (*first, *second)
+    }
+
+    // Consumes the pair. On the stack it is only two pointers, no
+    // matter how big `T` is, the values themselves live on the heap.
+    fn destroy(self) {
+        println!("Destroying Pair2 of {} bytes, pointing to 2 values of {} bytes",
+                 mem::size_of::<Pair2<T>>(), mem::size_of::<T>());
+    }
 }
 
 pub fn display_functions_and_methods() {
@@ -91,6 +157,14 @@ pub fn display_functions_and_methods() {
     // `rectangle.perimeter()` === `Rectangle::perimeter(&rectangle)`
     println!("Rectangle perimeter: {}", rectangle.perimeter());
     println!("Rectangle area: {}", rectangle.area());
+    assert_eq!(rectangle.checked_area(), Ok(12.0));
+
+    let huge = Rectangle {
+        p1: Point::origin(),
+        p2: Point::new(f64::MAX, f64::MAX),
+    };
+    println!("Huge rectangle area: {:?}", huge.checked_area());
+    assert!(huge.checked_area().is_err());
 
     let mut square = Rectangle {
         p1: Point::origin(),
@@ -104,8 +178,29 @@ pub fn display_functions_and_methods() {
     // Okay! Mutable objects can call mutable methods
     square.translate(1.0, 1.0);
 
+    // Scaling by 2 doubles both sides, so the area is 4 times bigger.
+    let area = square.area();
+    let scaled = square * 2.0;
+    println!("Scaled square area: {}", scaled.area());
+    assert_eq!(scaled.area(), 4.0 * area);
+
     let pair = Pair(Box::new(3), Box::new(6));
+
+    // `clone` leaves `pair` usable, `swapped` then consumes the clone.
+    let cloned = pair.clone();
+    assert_eq!(cloned, pair);
+    assert_eq!(pair.sum(), 9);
+
+    let swapped = cloned.swapped();
+    println!("{:?} swapped is {:?}", pair, swapped);
+    assert_eq!(swapped, Pair(Box::new(6), Box::new(3)));
+
     pair.destroy();
+
+    let generic_pair = Pair2(Box::new(1), Box::new(2));
+    assert_eq!(generic_pair.into_inner(), (1, 2));
+    Pair2(Box::new([0u64; 4]), Box::new([1u64; 4])).destroy();
+    assert_eq!(mem::size_of::<Pair2<[u64; 4]>>(), mem::size_of::<Pair2<u8>>());
 }
 
 // HOF - High Order Functions
@@ -145,6 +240,63 @@ pub fn display_high_order_function() {
     println!("functional style: {}", sum_of_squared_odd_numbers);
 }
 
+// The same functional style can be packaged up as a reusable adapter.
+// `scan` is like `fold` but yields the accumulated state after each item
+// instead of only the final value.
+fn running_sum(iter: impl Iterator<Item = i32>) -> impl Iterator<Item = i32> {
+    iter.scan(0, |total, number| {
+        *total += number;
+        Some(*total)
+    })
+}
+
+pub fn display_running_sum() {
+    let sums: Vec<i32> = running_sum([1, 2, 3].into_iter()).collect();
+
+    println!("running sum of [1, 2, 3]: {:?}", sums);
+    assert_eq!(sums, vec![1, 3, 6]);
+}
+
+// Yields the Fibonacci sequence 0, 1, 1, 2, 3, 5, ... and ends
+// once the next number would no longer fit in a `u64`.
+struct Fibonacci {
+    current: Option<u64>,
+    next: Option<u64>,
+}
+
+impl Fibonacci {
+    fn new() -> Fibonacci {
+        Fibonacci { current: Some(0), next: Some(1) }
+    }
+}
+
+impl Iterator for Fibonacci {
+    type Item = u64;
+
+    fn next(&mut self) -> Option<u64> {
+        let current = self.current?;
+
+        // an overflow becomes `None` and ends the iteration
+        // after the numbers that did fit.
+        self.current = self.next;
+        self.next = self.next.and_then(|next| next.checked_add(current));
+
+        Some(current)
+    }
+}
+
+pub fn display_fibonacci() {
+    let first_ten: Vec<u64> = Fibonacci::new().take(10).collect();
+
+    println!("first ten Fibonacci numbers: {:?}", first_ten);
+    assert_eq!(first_ten, vec![0, 1, 1, 2, 3, 5, 8, 13, 21, 34]);
+
+    // F(93) is the largest Fibonacci number a `u64` can hold.
+    let all: Vec<u64> = Fibonacci::new().collect();
+    assert_eq!(all.len(), 94);
+    assert_eq!(all.last(), Some(&12_200_160_415_121_876_738));
+}
+
 // Diverging Functions
 // Diverging functions never return. They are marked using !, which is an empty type.
 // function, which will never return the control back to the caller.
@@ -5,8 +5,11 @@ mod closures;
 fn main() {
     functions::display_functions_and_methods();
     functions::display_high_order_function();
+    functions::display_running_sum();
+    functions::display_fibonacci();
 
     closures::display_closure_capturing();
     closures::display_closure_as_input_parameters();
     closures::display_closure_as_output();
+    closures::display_closure_in_iterator_adapter();
 }
@@ -5,6 +5,11 @@
     a single function for many types.
 */
 
+use std::cmp::Reverse;
+use std::collections::{BinaryHeap, HashMap};
+use std::fmt::{self, Display};
+use std::hash::Hash;
+
 struct A;          // Concrete type `A`.
 struct S(A);       // Concrete type `S`.
 struct SGen<T>(T); // Generic type `SGen`.
@@ -23,6 +28,21 @@ fn gen_spec_i32(_s: SGen<i32>) {}
 // Because `SGen<T>` is preceded by `<T>`, this function is generic over `T`.
 fn generic<T>(_s: SGen<T>) {}
 
+// `SGen<T>` can only be displayed when `T` can be displayed,
+// the bound ties the output of the wrapper to its type parameter.
+impl<T: Display> Display for SGen<T> {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "SGen({})", self.0)
+    }
+}
+
+impl<T> SGen<T> {
+    // borrow the wrapped value of any type `T`.
+    fn inner(&self) -> &T {
+        &self.0
+    }
+}
+
 pub fn show_generic_function() {
     gen_spec_t(SGen(A));
     gen_spec_i32(SGen(40));
@@ -32,6 +52,39 @@ pub fn show_generic_function() {
 
     // Implicitly specified type of Tuple(&str, int)
     generic(SGen(("ball", 40)));
+
+    let wrapped = SGen(42);
+    println!("{} wraps {}", wrapped, wrapped.inner());
+    assert_eq!(format!("{}", SGen(42)), "SGen(42)");
+}
+
+// Generic over two type parameters, `A` and `B` can be different
+// types or the same one.
+fn pair_up<A, B>(a: A, b: B) -> (A, B) {
+    (a, b)
+}
+
+// Split a list of pairs into the list of first items and the list
+// of second items, the types of both halves are kept apart.
+fn unzip_pairs<A, B>(pairs: Vec<(A, B)>) -> (Vec<A>, Vec<B>) {
+    let mut firsts = Vec::with_capacity(pairs.len());
+    let mut seconds = Vec::with_capacity(pairs.len());
+
+    for (a, b) in pairs {
+        firsts.push(a);
+        seconds.push(b);
+    }
+
+    (firsts, seconds)
+}
+
+pub fn show_generic_pairs() {
+    let pairs = vec![pair_up(1, 'a'), pair_up(2, 'b')];
+    println!("pairs: {:?}", pairs);
+
+    let (numbers, letters) = unzip_pairs(pairs);
+    println!("unzipped into {:?} and {:?}", numbers, letters);
+    assert_eq!((numbers, letters), (vec![1, 2], vec!['a', 'b']));
 }
 
 // Similar to functions, implementations require care to remain generic.
@@ -62,10 +115,372 @@ impl<T> GenVal<T> {
     }
 }
 
+// `Val` and `GenVal<T>` both hold a value, an associated type lets
+// a single trait describe both: the implementor picks `Output`.
+trait Valued {
+    type Output;
+
+    fn value(&self) -> &Self::Output;
+}
+
+impl Valued for Val {
+    type Output = f64;
+
+    fn value(&self) -> &f64 {
+        &self.val
+    }
+}
+
+impl<T> Valued for GenVal<T> {
+    type Output = T;
+
+    fn value(&self) -> &T {
+        &self.gen_val
+    }
+}
+
+// Generic code can now take either type, as long as
+// the value it holds can be displayed.
+fn print_value<V: Valued>(v: &V) -> String where
+    V::Output: Display {
+    let printed = format!("value: {}", Valued::value(v));
+    println!("{}", printed);
+
+    printed
+}
+
 pub fn show_impl_with_generic() {
     let x = Val { val: 20.0 };
     let y = GenVal { gen_val: "ball" };
     let z = GenVal { gen_val: true };
 
     println!("{} {} {}", x.value(), y.value(), z.value());
-}
\ No newline at end of file
+
+    assert_eq!(print_value(&x), "value: 20");
+    assert_eq!(print_value(&y), "value: ball");
+    assert_eq!(print_value(&z), "value: true");
+}
+
+// Matrices as a slice of rows, multiplying an `n x m` matrix by
+// an `m x p` matrix gives an `n x p` matrix.
+// The number of columns in `a` must equal the number of rows in `b`.
+fn matmul(a: &[Vec<f64>], b: &[Vec<f64>]) -> Result<Vec<Vec<f64>>, String> {
+    let inner = b.len();
+    let columns = b.first().map_or(0, |row| row.len());
+
+    if b.iter().any(|row| row.len() != columns) {
+        return Err(String::from("rows of the second matrix have different lengths"));
+    }
+
+    if let Some(row) = a.iter().find(|row| row.len() != inner) {
+        return Err(format!(
+            "cannot multiply: a row of the first matrix has {} columns but the second matrix has {} rows",
+            row.len(), inner));
+    }
+
+    let product = a.iter()
+        .map(|row| {
+            (0..columns)
+                .map(|j| row.iter().zip(b).map(|(x, b_row)| x * b_row[j]).sum())
+                .collect()
+        })
+        .collect();
+
+    Ok(product)
+}
+
+pub fn show_matrix_multiplication() {
+    let a = vec![
+        vec![1.0, 2.0, 3.0],
+        vec![4.0, 5.0, 6.0],
+    ];
+    let b = vec![
+        vec![7.0, 8.0],
+        vec![9.0, 10.0],
+        vec![11.0, 12.0],
+    ];
+
+    let product = matmul(&a, &b);
+    println!("{:?} x {:?} = {:?}", a, b, product);
+    assert_eq!(product, Ok(vec![vec![58.0, 64.0], vec![139.0, 154.0]]));
+
+    // a 2x3 matrix can't be multiplied by another 2x3 matrix.
+    let mismatch = matmul(&a, &a);
+    println!("{:?} x {:?} = {:?}", a, a, mismatch);
+    assert!(mismatch.is_err());
+}
+
+// Floyd's tortoise and hare: walk the sequence `start, next(start), ...`
+// with one pointer moving a step at a time and another moving two.
+// If the sequence cycles the two pointers eventually meet inside the cycle,
+// walking once around from the meeting point gives the cycle length.
+// `None` is returned if they haven't met within `max_steps`.
+fn detect_cycle(start: usize, next: impl Fn(usize) -> usize, max_steps: usize) -> Option<usize> {
+    let mut tortoise = next(start);
+    let mut hare = next(next(start));
+    let mut steps = 1;
+
+    while tortoise != hare {
+        if steps >= max_steps {
+            return None;
+        }
+
+        tortoise = next(tortoise);
+        hare = next(next(hare));
+        steps += 1;
+    }
+
+    let mut length = 1;
+    let mut runner = next(tortoise);
+
+    while runner != tortoise {
+        runner = next(runner);
+        length += 1;
+    }
+
+    Some(length)
+}
+
+pub fn show_cycle_detection() {
+    // 0 -> 2 -> 4 -> 6 -> 8 -> 3 -> 5 -> 7 -> 3 -> ... has a cycle of 3.
+    let jumps = [2, 0, 4, 5, 6, 7, 8, 3, 3];
+    let cycle = detect_cycle(0, |i| jumps[i], 100);
+    println!("following {:?} from index 0 cycles with length {:?}", jumps, cycle);
+    assert_eq!(cycle, Some(3));
+
+    // counting up never repeats, we give up after `max_steps`.
+    let no_cycle = detect_cycle(0, |i| i + 1, 1_000);
+    println!("counting up from 0 cycles with length {:?}", no_cycle);
+    assert_eq!(no_cycle, None);
+}
+
+// Removes duplicates from an already sorted `Vec` in place.
+// `write` marks the end of the unique items kept so far while `read`
+// scans ahead, each new value is moved down next to the kept ones.
+// `T` only needs `PartialEq` as items are swapped, never copied.
+fn dedup_sorted<T: PartialEq>(slice: &mut Vec<T>) {
+    if slice.is_empty() {
+        return;
+    }
+
+    let mut write = 1;
+
+    for read in 1..slice.len() {
+        if slice[read] != slice[write - 1] {
+            slice.swap(read, write);
+            write += 1;
+        }
+    }
+
+    slice.truncate(write);
+}
+
+pub fn show_dedup_sorted() {
+    let mut numbers = vec![1, 1, 2, 3, 3, 3];
+    println!("sorted with duplicates: {:?}", numbers);
+
+    dedup_sorted(&mut numbers);
+    println!("deduplicated in place: {:?}", numbers);
+    assert_eq!(numbers, vec![1, 2, 3]);
+}
+
+// Can `nums` be split into two subsets with the same sum?
+// That is the case when some subset adds up to half of the total.
+// `reachable[s]` records whether a subset summing to `s` has been found,
+// each number extends every sum reached before it. Sums are walked from
+// the top down so a number is not used twice.
+// `reachable` has one entry per sum up to half the total, so large
+// numbers cost a lot of memory: numbers near `u32::MAX` need gigabytes.
+fn can_partition(nums: &[u32]) -> bool {
+    // summed as `u64` so even many large numbers can't overflow.
+    let total: u64 = nums.iter().map(|&num| num as u64).sum();
+
+    if total % 2 != 0 {
+        return false;
+    }
+
+    let target = (total / 2) as usize;
+    let mut reachable = vec![false; target + 1];
+    reachable[0] = true;
+
+    for &num in nums {
+        let num = num as usize;
+
+        for sum in (num..=target).rev() {
+            if reachable[sum - num] {
+                reachable[sum] = true;
+            }
+        }
+    }
+
+    reachable[target]
+}
+
+pub fn show_balanced_partition() {
+    let balanced = [1, 5, 11, 5];
+    let unbalanced = [1, 2, 3, 5];
+
+    println!("{:?} can be partitioned: {}", balanced, can_partition(&balanced));
+    println!("{:?} can be partitioned: {}", unbalanced, can_partition(&unbalanced));
+
+    assert!(can_partition(&balanced));
+    assert!(!can_partition(&unbalanced));
+    // the total is above `u32::MAX`, and odd.
+    assert!(!can_partition(&[u32::MAX, 2]));
+}
+
+// Counts the pairs `(i, j)` with `i < j` and `data[i] > data[j]`.
+// A copy of `data` is merge sorted, whenever an item from the right half
+// is placed before the items still left in the left half, it forms an
+// inversion with each one of them.
+fn count_inversions(data: &[i32]) -> u64 {
+    fn sort_and_count(items: Vec<i32>) -> (Vec<i32>, u64) {
+        if items.len() <= 1 {
+            return (items, 0);
+        }
+
+        let mut left = items;
+        let right = left.split_off(left.len() / 2);
+
+        let (left, left_count) = sort_and_count(left);
+        let (right, right_count) = sort_and_count(right);
+
+        let mut merged = Vec::with_capacity(left.len() + right.len());
+        let mut count = left_count + right_count;
+        let (mut i, mut j) = (0, 0);
+
+        while i < left.len() && j < right.len() {
+            if left[i] <= right[j] {
+                merged.push(left[i]);
+                i += 1;
+            } else {
+                merged.push(right[j]);
+                count += (left.len() - i) as u64;
+                j += 1;
+            }
+        }
+
+        merged.extend_from_slice(&left[i..]);
+        merged.extend_from_slice(&right[j..]);
+
+        (merged, count)
+    }
+
+    sort_and_count(data.to_vec()).1
+}
+
+pub fn show_count_inversions() {
+    let mixed = [2, 4, 1, 3, 5];
+    let sorted = [1, 2, 3, 4, 5];
+    let reversed = [5, 4, 3, 2, 1];
+
+    println!("{:?} has {} inversions", mixed, count_inversions(&mixed));
+    println!("{:?} has {} inversions", sorted, count_inversions(&sorted));
+    println!("{:?} has {} inversions", reversed, count_inversions(&reversed));
+
+    assert_eq!(count_inversions(&mixed), 3);
+    assert_eq!(count_inversions(&sorted), 0);
+    // every pair is inverted: 5 * 4 / 2
+    assert_eq!(count_inversions(&reversed), 10);
+}
+
+// Length of the longest strictly increasing subsequence.
+// `tails[k]` holds the smallest value that ends an increasing
+// subsequence of length `k + 1`, like the top cards of the piles in
+// patience sorting. `tails` stays sorted, so every value finds its pile
+// with a binary search and the number of piles is the answer.
+fn lis_length(data: &[i32]) -> usize {
+    let mut tails: Vec<i32> = Vec::new();
+
+    for &value in data {
+        // `Err` is where `value` would be inserted, an `Ok` means an equal
+        // value already ends a subsequence so it can't extend that one.
+        let pile = match tails.binary_search(&value) {
+            Ok(pile) | Err(pile) => pile,
+        };
+
+        if pile == tails.len() {
+            tails.push(value);
+        } else {
+            tails[pile] = value;
+        }
+    }
+
+    tails.len()
+}
+
+pub fn show_longest_increasing_subsequence() {
+    let data = [10, 9, 2, 5, 3, 7, 101, 18];
+    let decreasing = [5, 4, 3, 2, 1];
+
+    println!("longest increasing subsequence of {:?} has length {}", data, lis_length(&data));
+
+    assert_eq!(lis_length(&data), 4);
+    assert_eq!(lis_length(&[]), 0);
+    assert_eq!(lis_length(&decreasing), 1);
+}
+
+// Merges several sorted lists into one sorted list.
+// The heap holds the smallest item not yet taken from each list, together
+// with the index of the list it came from. `BinaryHeap` is a max-heap, so
+// items are wrapped in `Reverse` to pop the smallest one first.
+fn kway_merge<T: Ord>(mut lists: Vec<Vec<T>>) -> Vec<T> {
+    let total = lists.iter().map(Vec::len).sum();
+    let mut sources: Vec<_> = lists.drain(..).map(Vec::into_iter).collect();
+    let mut heap = BinaryHeap::new();
+
+    for (index, source) in sources.iter_mut().enumerate() {
+        if let Some(item) = source.next() {
+            heap.push(Reverse((item, index)));
+        }
+    }
+
+    let mut merged = Vec::with_capacity(total);
+
+    while let Some(Reverse((item, index))) = heap.pop() {
+        merged.push(item);
+
+        // refill from the list the item was taken from.
+        if let Some(next) = sources[index].next() {
+            heap.push(Reverse((next, index)));
+        }
+    }
+
+    merged
+}
+
+pub fn show_kway_merge() {
+    let lists = vec![vec![1, 4, 7], vec![2, 5, 8], vec![3, 6, 9]];
+    let merged = kway_merge(lists);
+    println!("merged sorted lists: {:?}", merged);
+    assert_eq!(merged, vec![1, 2, 3, 4, 5, 6, 7, 8, 9]);
+
+    let with_empty = vec![vec![], vec![2, 10], vec![], vec![1, 3]];
+    assert_eq!(kway_merge(with_empty), vec![1, 2, 3, 10]);
+    assert_eq!(kway_merge::<i32>(vec![]), vec![]);
+}
+
+// Partitions `items` into buckets of the items `key` maps to the same value.
+// Inside each bucket the items keep the order they had in `items`.
+fn group_by<T, K: Eq + Hash, F: Fn(&T) -> K>(items: Vec<T>, key: F) -> HashMap<K, Vec<T>> {
+    let mut groups: HashMap<K, Vec<T>> = HashMap::new();
+
+    for item in items {
+        groups.entry(key(&item)).or_default().push(item);
+    }
+
+    groups
+}
+
+pub fn show_group_by() {
+    let by_parity = group_by(vec![1, 2, 3, 4, 5, 6, 7], |n| n % 2 == 0);
+    println!("grouped by parity: {:?}", by_parity);
+
+    assert_eq!(by_parity.len(), 2);
+    assert_eq!(by_parity[&true], vec![2, 4, 6]);
+    assert_eq!(by_parity[&false], vec![1, 3, 5, 7]);
+
+    let by_length = group_by(vec!["a", "bb", "cc", "d"], |word| word.len());
+    assert_eq!(by_length[&1], vec!["a", "d"]);
+    assert_eq!(by_length[&2], vec!["bb", "cc"]);
+}
@@ -1,12 +1,26 @@
 mod functions;
 mod traits;
+mod math;
 
 fn main() {
     functions::show_generic_function();
+    functions::show_generic_pairs();
     functions::show_impl_with_generic();
+    functions::show_matrix_multiplication();
+    functions::show_cycle_detection();
+    functions::show_dedup_sorted();
+    functions::show_balanced_partition();
+    functions::show_count_inversions();
+    functions::show_longest_increasing_subsequence();
+    functions::show_kway_merge();
+    functions::show_group_by();
 
     traits::show_generic_trait();
     traits::show_generic_using_where();
     traits::show_generic_non_associative_type();
     traits::show_generic_with_associated_types();
+    traits::show_container_trait();
+    traits::show_impl_trait_argument();
+
+    math::show_factorial();
 }
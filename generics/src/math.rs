@@ -0,0 +1,35 @@
+/*
+    Recursion and overflow
+
+    The same computation can be written as a loop or as a function
+    calling itself. Both grow the result quickly, so instead of
+    panicking (debug builds) or silently wrapping (release builds),
+    checked multiplication turns an overflow into `None`.
+*/
+
+// 20! is the largest factorial that fits in a `u64`.
+fn factorial_iter(n: u64) -> Option<u64> {
+    (1..=n).try_fold(1u64, |acc, k| acc.checked_mul(k))
+}
+
+fn factorial_rec(n: u64) -> Option<u64> {
+    match n {
+        0 => Some(1),
+        // `?` stops as soon as a smaller factorial already overflowed.
+        _ => factorial_rec(n - 1)?.checked_mul(n),
+    }
+}
+
+pub fn show_factorial() {
+    for n in 0..=20 {
+        assert_eq!(factorial_iter(n), factorial_rec(n));
+    }
+
+    println!("5! = {:?}, 20! = {:?}", factorial_iter(5), factorial_rec(20));
+    assert_eq!(factorial_iter(5), Some(120));
+    assert_eq!(factorial_iter(20), Some(2_432_902_008_176_640_000));
+
+    // 21! doesn't fit in a `u64`.
+    assert_eq!(factorial_iter(21), None);
+    assert_eq!(factorial_rec(21), None);
+}
@@ -1,4 +1,5 @@
 use std::fmt::{ Display, Debug};
+use std::ops::Add;
 
 // Non-copyable types.
 struct Empty;
@@ -78,6 +79,10 @@ pub fn show_generic_trait() {
 
     compare_prints(&string);
     compare_types(&array, &vec);
+
+    let summary = report(&"words", &3.5);
+    println!("{}", summary);
+    assert_eq!(summary, "a: words (\"words\")\nb: 3.5 (3.5)");
 }
 
 // using multiple bounds
@@ -89,6 +94,15 @@ fn compare_types<T: Debug, U: Debug>(t: &T, u: &U) {
     println!("t: {:?}, u: {:?}", t, u);
 }
 
+// Like `compare_types` but both values need two bounds each, which
+// reads better in a `where` clause. The report is returned instead
+// of printed, one line per value.
+fn report<T, U>(a: &T, b: &U) -> String where
+    T: Display + Debug,
+    U: Display + Debug {
+    format!("a: {} ({:?})\nb: {} ({:?})", a, a, b, b)
+}
+
 // this could be better display
 // impl <A: TraitB + TraitC, D: TraitE + TraitF> MyTrait<A, D> for YourType {}
 
@@ -113,9 +127,26 @@ impl<T> PrintInOption for T where
     }
 }
 
+// A `where` clause on a plain function: every item is cloned
+// to appear twice, and printed with `{:?}`.
+fn duplicate<T>(items: &[T]) -> Vec<T> where
+    T: Clone + Debug {
+    let mut doubled = Vec::with_capacity(items.len() * 2);
+
+    for item in items {
+        println!("duplicating {:?}", item);
+        doubled.push(item.clone());
+        doubled.push(item.clone());
+    }
+
+    doubled
+}
+
 pub fn show_generic_using_where() {
     let vec = vec![1, 2, 3];
     vec.print_in_option();
+
+    assert_eq!(duplicate(&[1, 2]), vec![1, 1, 2, 2]);
 }
 
 // Associative Types helps improve the readability of your code
@@ -131,9 +162,18 @@ trait ContainsA<A, B> {
     fn last(&self) -> i32;  // Doesn't explicitly require `A` or `B`.
 }
 
-struct ContainerA(i32, i32);
+struct ContainerA<T>(T, T);
 
-impl ContainsA<i32, i32> for ContainerA {
+impl<T> ContainerA<T> {
+    // Works for any element that can be added and copied out
+    // of the container, integers and floats alike.
+    fn pair_sum(&self) -> T where
+        T: Add<Output = T> + Copy {
+        self.0 + self.1
+    }
+}
+
+impl ContainsA<i32, i32> for ContainerA<i32> {
     fn contains(&self, number_1: &i32, number_2: &i32) -> bool {
         (&self.0 == number_1) && (&self.1 == number_2)
     }
@@ -167,6 +207,12 @@ pub fn show_generic_non_associative_type() {
     println!("Last number: {}", container.last());
 
     println!("The difference is: {}", difference(&container));
+    println!("The sum is: {}", container.pair_sum());
+
+    assert!(container.contains(&3, &10));
+    assert!(!container.contains(&10, &3));
+    assert_eq!(container.pair_sum(), 13);
+    assert_eq!(ContainerA(1.5, 2.25).pair_sum(), 3.75);
 }
 
 // The Problem of A trait that is generic over its container type
@@ -230,4 +276,61 @@ pub fn show_generic_with_associated_types() {
     println!("Last number: {}", container.last());
 
     println!("The difference is: {}", difference_b(&container));
-}
\ No newline at end of file
+}
+
+// A uniform way to ask any collection how many items it holds.
+// Implementors only need `len`, `is_empty` comes for free
+// from the default method.
+trait Container {
+    fn len(&self) -> usize;
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+}
+
+impl<T> Container for Vec<T> {
+    fn len(&self) -> usize {
+        // `Vec` has its own `len`, call it rather than this trait method.
+        Vec::len(self)
+    }
+}
+
+// Works with any implementor of `Container`.
+fn describe<C: Container>(container: &C) -> String {
+    if container.is_empty() {
+        String::from("empty")
+    } else {
+        format!("holds {} items", container.len())
+    }
+}
+
+pub fn show_container_trait() {
+    let numbers = vec![1, 2, 3];
+    let nothing: Vec<i32> = Vec::new();
+
+    println!("numbers {}, nothing is {}", describe(&numbers), describe(&nothing));
+
+    assert_eq!(Container::len(&numbers), 3);
+    assert!(!Container::is_empty(&numbers));
+    assert_eq!(Container::len(&nothing), 0);
+    assert!(Container::is_empty(&nothing));
+}
+
+// `impl Trait` in argument position is shorthand for a generic
+// parameter with a bound: any type that can be turned into an
+// iterator of `i32` can be summed.
+fn sum_all(items: impl IntoIterator<Item = i32>) -> i32 {
+    items.into_iter().sum()
+}
+
+pub fn show_impl_trait_argument() {
+    let from_vec = sum_all(vec![1, 2, 3]);
+    let from_array = sum_all([4, 5, 6]);
+    let from_range = sum_all(1..=10);
+
+    println!("sums: vec {}, array {}, range {}", from_vec, from_array, from_range);
+    assert_eq!(from_vec, 6);
+    assert_eq!(from_array, 15);
+    assert_eq!(from_range, 55);
+}
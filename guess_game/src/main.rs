@@ -1,32 +1,149 @@
 use rand::Rng;
 use std::cmp::Ordering;
+use std::convert::TryFrom;
+use std::fmt;
+use std::env;
 use std::io;
 
-fn main() {
-    println!("Guess the number!");
-    let secret_number = rand::thread_rng().gen_range(1, 101);
-    let mut input_attempt = 5;
+// How a game ended, returned by `play` so the result
+// can be reported in one place.
+enum GameResult {
+    Won { attempts: u32 },
+    Lost { secret_number: u32 },
+}
+
+impl fmt::Display for GameResult {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        match self {
+            GameResult::Won { attempts } => write!(f, "Won in {} attempts", attempts),
+            GameResult::Lost { secret_number } => write!(f, "Lost (secret was {})", secret_number),
+        }
+    }
+}
+
+// How hard the game is, chosen with `--difficulty <easy|normal|hard>`.
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Difficulty {
+    Easy,
+    Normal,
+    Hard,
+}
+
+// The settings a game is played with: the secret number is
+// between `min` and `max`, both included.
+#[derive(Debug, PartialEq)]
+struct GameConfig {
+    min: u32,
+    max: u32,
+    attempts: u32,
+}
+
+fn config_for(d: Difficulty) -> GameConfig {
+    match d {
+        Difficulty::Easy => GameConfig { min: 1, max: 50, attempts: 8 },
+        Difficulty::Normal => GameConfig { min: 1, max: 100, attempts: 5 },
+        Difficulty::Hard => GameConfig { min: 1, max: 500, attempts: 5 },
+    }
+}
+
+// Look for `--difficulty <level>` in the command-line arguments,
+// the game is played on `Normal` when it is missing or not recognised.
+fn difficulty_from_args(args: &[String]) -> Difficulty {
+    let level = args.iter()
+        .position(|arg| arg == "--difficulty")
+        .and_then(|i| args.get(i + 1));
+
+    match level.map(|level| level.to_lowercase()).as_deref() {
+        Some("easy") => Difficulty::Easy,
+        Some("hard") => Difficulty::Hard,
+        Some("normal") | None => Difficulty::Normal,
+        Some(other) => {
+            println!("Unknown difficulty `{}`, playing on normal", other);
+            Difficulty::Normal
+        }
+    }
+}
+
+// What a line typed in by the player turned out to be.
+#[derive(Debug, PartialEq)]
+enum InputKind {
+    Empty,
+    NotANumber,
+    // The number as it was typed, it may not even fit in an `i64`.
+    OutOfRange(String),
+    Valid(u32),
+}
+
+fn classify_input(raw: &str, min: u32, max: u32) -> InputKind {
+    let trimmed = raw.trim();
+
+    if trimmed.is_empty() {
+        return InputKind::Empty;
+    }
+
+    // It results the enum Result that needs to be handled.
+    // Parsing as `i64` lets a negative number be reported as out of
+    // range instead of as something that isn't a number at all.
+    match trimmed.parse::<i64>() {
+        Ok(num) => match u32::try_from(num) {
+            Ok(guess) if (min..=max).contains(&guess) => InputKind::Valid(guess),
+            _ => InputKind::OutOfRange(trimmed.to_string()),
+        },
+        // Digits that don't fit in an `i64` are still a number, just a huge one.
+        Err(_) if is_integer(trimmed) => InputKind::OutOfRange(trimmed.to_string()),
+        Err(_) => InputKind::NotANumber,
+    }
+}
+
+// An optional leading `-` followed by one or more ASCII digits.
+fn is_integer(text: &str) -> bool {
+    let digits = text.strip_prefix('-').unwrap_or(text);
+
+    !digits.is_empty() && digits.bytes().all(|b| b.is_ascii_digit())
+}
+
+fn play(secret_number: u32, config: &GameConfig) -> GameResult {
+    let GameConfig { min, max, attempts: max_attempts } = *config;
+    let mut input_attempt = max_attempts;
 
     loop {
         // check the game attempt
         if input_attempt == 0 {
             println!("Oops! You are out of luck");
             println!("The secret number is {}, better luck next time", secret_number);
-            return;
+            return GameResult::Lost { secret_number };
         }
         println!("Hi! you have {} attempt(s)", input_attempt);
         println!("Please input your guess.");
 
         let mut guess = String::new();
 
-        io::stdin()
+        let read = io::stdin()
             .read_line(&mut guess)
             .expect("Failed to read line");
 
-        // It results the enum Result that needs to be handled
-        let guess: u32 = match guess.trim().parse() {
-            Ok(num) => num,
-            Err(_) => continue,
+        // Nothing was read: the input is closed and no guess will ever come.
+        if read == 0 {
+            println!("No more input, the game is over.");
+            return GameResult::Lost { secret_number };
+        }
+
+        // Input that isn't a number doesn't cost an attempt.
+        let guess: u32 = match classify_input(&guess, min, max) {
+            InputKind::Valid(num) => num,
+            InputKind::Empty => {
+                println!("You didn't type anything, try again.");
+                continue;
+            }
+            InputKind::NotANumber => {
+                println!("That's not a number, try again.");
+                continue;
+            }
+            InputKind::OutOfRange(num) => {
+                println!("{} is not between {} and {}", num, min, max);
+                input_attempt -= 1;
+                continue;
+            }
         };
 
         println!("You guessed: {}", guess);
@@ -35,9 +152,97 @@ fn main() {
             Ordering::Less => println!("Too small"),
             Ordering::Equal => {
                 println!("You win!");
-                break;
+                return GameResult::Won { attempts: max_attempts - input_attempt + 1 };
             }
         }
         input_attempt = input_attempt - 1;
     }
 }
+
+// Only a clear yes starts another game: `y`, `Y` or `yes`.
+fn wants_replay(input: &str) -> bool {
+    matches!(input.trim(), "y" | "Y" | "yes")
+}
+
+fn main() {
+    let args: Vec<String> = env::args().collect();
+    let difficulty = difficulty_from_args(&args);
+    let config = config_for(difficulty);
+
+    let (mut wins, mut losses) = (0, 0);
+
+    loop {
+        println!("Guess the number between {} and {}! ({:?})", config.min, config.max, difficulty);
+        let secret_number = rand::thread_rng().gen_range(config.min, config.max + 1);
+
+        let result = play(secret_number, &config);
+        println!("{}", result);
+
+        match result {
+            GameResult::Won { .. } => wins += 1,
+            GameResult::Lost { .. } => losses += 1,
+        }
+
+        println!("play again? (y/n)");
+        let mut answer = String::new();
+
+        io::stdin()
+            .read_line(&mut answer)
+            .expect("Failed to read line");
+
+        if !wants_replay(&answer) {
+            break;
+        }
+    }
+
+    println!("You won {} and lost {} game(s)", wins, losses);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn classify_input_kinds() {
+        assert_eq!(classify_input("abc", 1, 100), InputKind::NotANumber);
+        assert_eq!(classify_input("  ", 1, 100), InputKind::Empty);
+        assert_eq!(classify_input("150", 1, 100), InputKind::OutOfRange(String::from("150")));
+        assert_eq!(classify_input("42\n", 1, 100), InputKind::Valid(42));
+        assert_eq!(classify_input("-5", 1, 100), InputKind::OutOfRange(String::from("-5")));
+        assert_eq!(classify_input("99999999999999999999", 1, 100),
+                   InputKind::OutOfRange(String::from("99999999999999999999")));
+        assert_eq!(classify_input("-", 1, 100), InputKind::NotANumber);
+    }
+    #[test]
+    fn game_result_display() {
+        assert_eq!(GameResult::Won { attempts: 3 }.to_string(), "Won in 3 attempts");
+        assert_eq!(GameResult::Lost { secret_number: 42 }.to_string(), "Lost (secret was 42)");
+    }
+    #[test]
+    fn difficulty_configs() {
+        assert_eq!(config_for(Difficulty::Easy), GameConfig { min: 1, max: 50, attempts: 8 });
+        assert_eq!(config_for(Difficulty::Normal), GameConfig { min: 1, max: 100, attempts: 5 });
+        assert_eq!(config_for(Difficulty::Hard), GameConfig { min: 1, max: 500, attempts: 5 });
+    }
+    #[test]
+    fn difficulty_from_command_line() {
+        let args = |list: &[&str]| list.iter().map(|arg| arg.to_string()).collect::<Vec<_>>();
+
+        assert_eq!(difficulty_from_args(&args(&["guess_game"])), Difficulty::Normal);
+        assert_eq!(difficulty_from_args(&args(&["guess_game", "--difficulty"])), Difficulty::Normal);
+        assert_eq!(difficulty_from_args(&args(&["guess_game", "--difficulty", "extreme"])), Difficulty::Normal);
+        assert_eq!(difficulty_from_args(&args(&["guess_game", "--difficulty", "HARD"])), Difficulty::Hard);
+        assert_eq!(difficulty_from_args(&args(&["guess_game", "--difficulty", "Easy"])), Difficulty::Easy);
+    }
+    #[test]
+    fn replay_answers() {
+        assert!(wants_replay("y"));
+        assert!(wants_replay("Y"));
+        assert!(wants_replay("yes\n"));
+        assert!(!wants_replay(""));
+        assert!(!wants_replay("n"));
+        assert!(!wants_replay("yep"));
+        assert!(!wants_replay("YES"));
+        assert!(!wants_replay("Yes"));
+    }
+}
@@ -9,6 +9,9 @@
  the object cannot be destroyed.
 */
 
+use std::cmp::Ordering;
+use std::ops::AddAssign;
+
 // This function takes ownership of a box and destroys it
 // parameter is passed by value.
 fn eat_box_i32(boxed_i32: Box<i32>) {
@@ -68,6 +71,20 @@ struct Book {
     year: u32,
 }
 
+// Books are compared by publication year only, so two different
+// books from the same year are equal as far as ordering goes.
+impl PartialEq for Book {
+    fn eq(&self, other: &Self) -> bool {
+        self.year == other.year
+    }
+}
+
+impl PartialOrd for Book {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        self.year.partial_cmp(&other.year)
+    }
+}
+
 // This function takes a reference to a book
 // no ownership is transferred, just borrowing not moving.
 // I have a read access.
@@ -108,6 +125,61 @@ pub fn show_borrowing_with_mutable_reference() {
     // new_edition(&mut immutabook);
 }
 
+pub fn show_books_by_year() {
+    let mut books = [
+        Book { author: "Douglas Hofstadter", title: "Gödel, Escher, Bach", year: 1979 },
+        Book { author: "Steve Klabnik", title: "The Rust Programming Language", year: 2018 },
+        Book { author: "Donald Knuth", title: "The Art of Computer Programming", year: 1968 },
+    ];
+
+    // `PartialOrd` only gives an `Option<Ordering>`, the years
+    // always compare though, so it is never `None`.
+    books.sort_by(|a, b| a.partial_cmp(b).unwrap());
+
+    let years: Vec<u32> = books.iter().map(|book| book.year).collect();
+    println!("books by year: {:?}", years);
+    assert_eq!(years, vec![1968, 1979, 2018]);
+    assert!(books[0] < books[1]);
+    assert_eq!(books[1].title, "Gödel, Escher, Bach");
+}
+
+// A `Book` that keeps every edition year it has had.
+// `Vec` isn't `Copy`, so neither is `EditedBook`: it gets moved
+// unless it is borrowed, and updating it needs a mutable borrow.
+#[derive(Debug)]
+struct EditedBook {
+    author: &'static str,
+    title: &'static str,
+    editions: Vec<u32>,
+}
+
+impl EditedBook {
+    fn new(author: &'static str, title: &'static str, year: u32) -> EditedBook {
+        EditedBook { author, title, editions: vec![year] }
+    }
+
+    // `&mut self` lets us record a new edition without taking ownership.
+    fn new_edition(&mut self, year: u32) {
+        self.editions.push(year);
+        println!("I mutably borrowed {} - {} edition", self.title, year);
+    }
+
+    fn latest_edition(&self) -> Option<u32> {
+        self.editions.last().copied()
+    }
+}
+
+pub fn show_edition_history() {
+    let mut book = EditedBook::new("Douglas Hofstadter", "Gödel, Escher, Bach", 1979);
+
+    book.new_edition(1999);
+    book.new_edition(2014);
+
+    println!("{} by {} has editions {:?}", book.title, book.author, book.editions);
+    assert_eq!(book.editions, vec![1979, 1999, 2014]);
+    assert_eq!(book.latest_edition(), Some(2014));
+}
+
 // Aliasing
 // Data can be immutably borrowed any number of times, but while immutably borrowed,
 // the original data can't be mutably borrowed.
@@ -121,6 +193,47 @@ struct Point {
     z: i32,
 }
 
+// A floating point version of `Point`, used for results
+// which aren't whole numbers.
+#[derive(Debug, PartialEq)]
+struct Point3D {
+    x: f64,
+    y: f64,
+    z: f64,
+}
+
+impl Point {
+    // The Euclidean distance of the point from the origin.
+    fn magnitude(&self) -> f64 {
+        let (x, y, z) = (self.x as f64, self.y as f64, self.z as f64);
+
+        (x * x + y * y + z * z).sqrt()
+    }
+
+    // The point scaled to a magnitude of 1 in the same direction.
+    // The zero vector has no direction, so it is returned as it is.
+    fn normalized(&self) -> Point3D {
+        let magnitude = self.magnitude();
+        let (x, y, z) = (self.x as f64, self.y as f64, self.z as f64);
+
+        if magnitude == 0.0 {
+            return Point3D { x, y, z };
+        }
+
+        Point3D { x: x / magnitude, y: y / magnitude, z: z / magnitude }
+    }
+}
+
+// `point += other` translates `point` by `other`. `add_assign` takes
+// `&mut self`, so the left-hand side is mutably borrowed for the call.
+impl AddAssign for Point {
+    fn add_assign(&mut self, other: Point) {
+        self.x += other.x;
+        self.y += other.y;
+        self.z += other.z;
+    }
+}
+
 pub fn show_borrowing_scenarios() {
     let mut point = Point { x: 0, y: 0, z: 0 };
 
@@ -167,4 +280,27 @@ pub fn show_borrowing_scenarios() {
     let new_borrowed_point = &point;
     println!("Point now has coordinates: ({}, {}, {})",
              new_borrowed_point.x, new_borrowed_point.y, new_borrowed_point.z);
-}
\ No newline at end of file
+}
+
+pub fn show_point_magnitude() {
+    let point = Point { x: 3, y: 4, z: 0 };
+
+    // both methods only need to borrow `point`.
+    println!("Point ({}, {}, {}) has magnitude {} and direction {:?}",
+             point.x, point.y, point.z, point.magnitude(), point.normalized());
+    assert_eq!(point.magnitude(), 5.0);
+    assert_eq!(point.normalized(), Point3D { x: 0.6, y: 0.8, z: 0.0 });
+
+    let origin = Point { x: 0, y: 0, z: 0 };
+    assert_eq!(origin.magnitude(), 0.0);
+    assert_eq!(origin.normalized(), Point3D { x: 0.0, y: 0.0, z: 0.0 });
+}
+
+pub fn show_point_translation() {
+    let mut point = Point { x: 1, y: 2, z: 3 };
+
+    point += Point { x: 5, y: 2, z: 1 };
+
+    println!("Point moved to ({}, {}, {})", point.x, point.y, point.z);
+    assert_eq!((point.x, point.y, point.z), (6, 4, 4));
+}
@@ -22,6 +22,7 @@
 */
 
 use std::fmt::Debug;
+use std::sync::atomic::{AtomicU32, Ordering};
 
 // `print_refs` takes two references to `i32` which have different
 // lifetimes `'a` and `'b`. These two lifetimes must both be at
@@ -75,6 +76,15 @@ fn add_two<'a>(value: &'a mut i32) {
     *value += 2;
 }
 
+// Both mutable references share the lifetime `'a`, so both values
+// must stay borrowed for as long as the function needs them.
+// Swaps the values behind the references when the first is the greater.
+fn swap_if_greater<'a>(a: &'a mut i32, b: &'a mut i32) {
+    if *a > *b {
+        std::mem::swap(a, b);
+    }
+}
+
 // Multiple elements with different lifetimes. In this case, it
 // would be fine for both to have the same lifetime `'a`, but
 // in more complex cases, different lifetimes may be required.
@@ -82,10 +92,45 @@ fn print_multi<'a, 'b>(x: &'a i32, y: &'b i32) {
     println!("`print_multi`: x is {}, y is {}", x, y);
 }
 
+pub fn show_swap_if_greater() {
+    let (mut small, mut big) = (1, 2);
+    swap_if_greater(&mut small, &mut big);
+    println!("`swap_if_greater`: ordered pair stays ({}, {})", small, big);
+    assert_eq!((small, big), (1, 2));
+
+    let (mut first, mut second) = (9, 4);
+    swap_if_greater(&mut first, &mut second);
+    println!("`swap_if_greater`: out of order pair becomes ({}, {})", first, second);
+    assert_eq!((first, second), (4, 9));
+}
+
 // Returning references that have been passed in is acceptable.
 // However, the correct lifetime must be returned.
 fn pass_x<'a, 'b>(x: &'a i32, _: &'b i32) -> &'a i32 { x }
 
+// Either input may be returned, so both must share the lifetime of
+// the output: the result is only valid while both are borrowed.
+// On a tie the first one is returned.
+fn longest<'a>(x: &'a str, y: &'a str) -> &'a str {
+    if y.len() > x.len() { y } else { x }
+}
+
+pub fn show_longest() {
+    let long = String::from("a long string");
+
+    {
+        let short = String::from("short");
+        let result = longest(&long, &short);
+        println!("`longest`: {}", result);
+        assert_eq!(result, "a long string");
+        assert_eq!(longest(&short, &long), "a long string");
+    }
+
+    // equal lengths, the first one wins.
+    let (first, second) = ("same", "size");
+    assert!(std::ptr::eq(longest(first, second), first));
+}
+
 //fn invalid_output<'a>() -> &'a String { &String::from("foo") }
 // The above is invalid: `'a` must live longer than the function.
 // Here, `&String::from("foo")` would create a `String`, followed by a
@@ -93,7 +138,8 @@ fn pass_x<'a, 'b>(x: &'a i32, _: &'b i32) -> &'a i32 { x }
 // a reference to invalid data to be returned.
 
 // Methods are annotated similarly to functions:
-#[derive(Debug)]
+// The default owner has no names yet: `Owner("", "")`.
+#[derive(Debug, Default)]
 struct Owner(&'static str, &'static str);
 
 impl Owner {
@@ -106,6 +152,36 @@ impl Owner {
     }
 }
 
+// Builds an `Owner` one name at a time instead of positionally.
+// Each step takes the builder by value and hands it back, so the
+// calls can be chained. The names end up in `Owner`, so they have
+// to be `'static` here too.
+#[derive(Default)]
+struct OwnerBuilder {
+    first: &'static str,
+    last: &'static str,
+}
+
+impl OwnerBuilder {
+    fn new() -> OwnerBuilder {
+        Default::default()
+    }
+
+    fn first(mut self, first: &'static str) -> OwnerBuilder {
+        self.first = first;
+        self
+    }
+
+    fn last(mut self, last: &'static str) -> OwnerBuilder {
+        self.last = last;
+        self
+    }
+
+    fn build(self) -> Owner {
+        Owner(self.first, self.last)
+    }
+}
+
 pub fn show_functions_with_lifetime() {
     let x = 7;
     let y = 9;
@@ -126,6 +202,20 @@ pub fn show_functions_with_lifetime() {
     owner.print_name();
 }
 
+pub fn show_owner_builder() {
+    let nobody = Owner::default();
+    nobody.print_name();
+    assert_eq!((nobody.0, nobody.1), ("", ""));
+
+    let owner = OwnerBuilder::new().first("Lumex").last("Ralph").build();
+    owner.print_name();
+    assert_eq!((owner.0, owner.1), ("Lumex", "Ralph"));
+
+    // a name that is never set keeps its default.
+    let first_only = OwnerBuilder::new().first("Lumex").build();
+    assert_eq!((first_only.0, first_only.1), ("Lumex", ""));
+}
+
 // Annotation of lifetimes in structures are also similar to functions:
 
 // A type `Borrowed` which houses a reference to an
@@ -133,6 +223,20 @@ pub fn show_functions_with_lifetime() {
 #[derive(Debug)]
 struct Borrowed<'a>(&'a i32);
 
+impl<'a> Borrowed<'a> {
+    fn get(&self) -> i32 {
+        *self.0
+    }
+}
+
+// Two `Borrowed` are equal when the numbers they point to are,
+// even if they borrow from different places.
+impl<'a> PartialEq for Borrowed<'a> {
+    fn eq(&self, other: &Self) -> bool {
+        self.0 == other.0
+    }
+}
+
 // Similarly, both references here must outlive this structure.
 #[derive(Debug)]
 struct NamedBorrow<'a> {
@@ -140,6 +244,38 @@ struct NamedBorrow<'a> {
     y: &'a i32,
 }
 
+impl<'a> NamedBorrow<'a> {
+    fn sum(&self) -> i32 {
+        *self.x + *self.y
+    }
+
+    // The returned reference is one of the fields, so it lives as long
+    // as `'a`, not as long as the borrow of `self`.
+    fn max(&self) -> &'a i32 {
+        if self.x >= self.y {
+            self.x
+        } else {
+            self.y
+        }
+    }
+}
+
+// A part of some text, borrowed rather than copied out of it.
+#[derive(Debug)]
+struct ImportantExcerpt<'a> {
+    part: &'a str,
+}
+
+impl<'a> ImportantExcerpt<'a> {
+    // No lifetimes are written out here. Since one of the inputs is
+    // `&self`, elision gives the output the lifetime of `&self`, not
+    // the one of `announcement`, which is fine as `part` is returned.
+    fn announce_and_return_part(&self, announcement: &str) -> &str {
+        println!("Attention please: {}", announcement);
+        self.part
+    }
+}
+
 // An enum which is either an `i32` or a reference to one.
 #[derive(Debug)]
 enum Either<'a> {
@@ -241,6 +377,34 @@ pub fn show_lifetime_in_struct() {
     }
 }
 
+pub fn show_lifetime_struct_methods() {
+    let x = 18;
+    let y = 15;
+
+    let double = NamedBorrow { x: &x, y: &y };
+
+    println!("sum of {:?} is {}", double, double.sum());
+    println!("max of {:?} is {}", double, double.max());
+    assert_eq!(double.sum(), 33);
+    // the larger reference is `&x` itself, not a copy of its value.
+    assert!(std::ptr::eq(double.max(), &x));
+
+    let z = 18;
+    let (from_x, from_z) = (Borrowed(&x), Borrowed(&z));
+
+    println!("{:?} == {:?} is {}", from_x, from_z, from_x == from_z);
+    assert_eq!(from_x, from_z);
+    assert_ne!(from_x, Borrowed(&y));
+    assert_eq!(from_x.get(), 18);
+    let novel = String::from("Call me Ishmael. Some years ago...");
+    let first_sentence = novel.split('.').next().unwrap();
+    let excerpt = ImportantExcerpt { part: first_sentence };
+
+    let part = excerpt.announce_and_return_part("an excerpt follows");
+    println!("{:?} returned {:?}", excerpt, part);
+    assert_eq!(part, "Call me Ishmael");
+}
+
 
 // Static Lifetime
 // Rust has a few reserved lifetime names. One of those is 'static.
@@ -269,6 +433,20 @@ fn coerce_static<'a>(_: &'a i32) -> &'a i32 {
     &NUM
 }
 
+// A `static` can't be assigned to without `unsafe`, but an atomic
+// mutates through a shared reference, so `'static` state can still
+// change safely, even across threads.
+static GLOBAL_COUNTER: AtomicU32 = AtomicU32::new(0);
+
+// Returns the value of the counter after it was incremented.
+fn increment_global() -> u32 {
+    GLOBAL_COUNTER.fetch_add(1, Ordering::SeqCst) + 1
+}
+
+fn global_value() -> u32 {
+    GLOBAL_COUNTER.load(Ordering::SeqCst)
+}
+
 // Trait bound
 // As a trait bound, it means the type does not contain any non-static references,
 // this means it contains `static` references, it'll exist through the lifetime
@@ -329,6 +507,14 @@ pub fn show_static_lifetime_reference() {
     // since static lifetime stays till running program, NUM is still accessible
     println!("NUM: {} stays accessible", NUM);
 
+    // the counter is shared by the whole program, so start
+    // from whatever it was before.
+    let before = global_value();
+    assert_eq!(increment_global(), before + 1);
+    assert_eq!(increment_global(), before + 2);
+    println!("GLOBAL_COUNTER went from {} to {}", before, global_value());
+    assert_eq!(global_value(), before + 2);
+
     // Trait Bound
 
     // i is owned and contains no references, thus it's 'static lifetime:
@@ -21,4 +21,22 @@ fn main() {
     // lifetime::show_functions_with_lifetime();
     // lifetime::show_lifetime_in_struct();
     lifetime::show_static_lifetime_reference();
+
+    raii::show_drop_order();
+    raii::show_scoped_timer();
+
+    borrowing::show_edition_history();
+    borrowing::show_point_magnitude();
+    borrowing::show_books_by_year();
+    borrowing::show_point_translation();
+
+    ref_pattern::show_first_word_ref();
+
+    ownership::show_clone_into_vec();
+    ownership::show_take_name();
+
+    lifetime::show_swap_if_greater();
+    lifetime::show_longest();
+    lifetime::show_lifetime_struct_methods();
+    lifetime::show_owner_builder();
 }
@@ -76,6 +76,26 @@ pub fn show_mutability() {
     println!("mutable_box now contains {}", mutable_box);
 }
 
+// Cloning instead of moving
+// `item` is only borrowed, each element of the vector is a new
+// value made by `clone`, so the caller still owns the original.
+fn clone_into_vec<T: Clone>(item: &T, n: usize) -> Vec<T> {
+    (0..n).map(|_| item.clone()).collect()
+}
+
+pub fn show_clone_into_vec() {
+    let greeting = String::from("hello");
+    let greetings = clone_into_vec(&greeting, 3);
+
+    // `greeting` was never moved, so it is still usable.
+    println!("{} was cloned into {:?}", greeting, greetings);
+    assert_eq!(greetings.len(), 3);
+    assert!(greetings.iter().all(|g| *g == greeting));
+
+    // a move would hand over the same heap buffer, clones have their own.
+    assert!(greetings.iter().all(|g| g.as_ptr() != greeting.as_ptr()));
+}
+
 // Partial Moves
 // Within the destructuring of a single variable, both by-move and by-reference
 // pattern bindings can be used at the same time. Doing this will result in a
@@ -118,3 +138,27 @@ pub fn show_partial_moves() {
     // `person` cannot be used but `person.age` can be used as it is not moved
     println!("The person's age from person struct is {}", person.age);
 }
+
+// Destructuring by value moves every field out, `person` is consumed
+// and the caller becomes the owner of the name and the boxed age.
+fn take_name(person: Person) -> (String, Box<u8>) {
+    let Person { name, age } = person;
+
+    (name, age)
+}
+
+pub fn show_take_name() {
+    let person = Person {
+        name: String::from("Lumex"),
+        age: Box::new(23),
+    };
+
+    let (name, age) = take_name(person);
+
+    // Error! `person` was moved into `take_name`.
+    // println!("The person struct is {:?}", person);
+
+    println!("took {} aged {} out of the person", name, age);
+    assert_eq!(name, "Lumex");
+    assert_eq!(*age, 23);
+}
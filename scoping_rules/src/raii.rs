@@ -5,6 +5,10 @@
     called and its owned resources are freed.
 */
 
+use std::cell::{Cell, RefCell};
+use std::rc::Rc;
+use std::time::{Duration, Instant};
+
 fn create_box() {
     // Allocate an integer on the heap
     let _box1 = Box::new(45_i32);
@@ -22,11 +26,32 @@ fn create_box() {
 // The destructor is called when the resource goes out of scope.
 // This trait is not required to be implemented for every type,
 // only implement it for your type if you require its own destructor logic
-struct ToDrop;
+//
+// Giving every `ToDrop` a name lets us tell the instances apart
+// and see the order they are dropped in. The optional `sink` is a
+// shared log, every drop records "dropped <name>" into it.
+struct ToDrop {
+    name: String,
+    sink: Option<Rc<RefCell<Vec<String>>>>,
+}
+
+impl ToDrop {
+    fn new(name: &str) -> ToDrop {
+        ToDrop { name: String::from(name), sink: None }
+    }
+
+    fn with_sink(name: &str, sink: &Rc<RefCell<Vec<String>>>) -> ToDrop {
+        ToDrop { name: String::from(name), sink: Some(Rc::clone(sink)) }
+    }
+}
 
 impl Drop for ToDrop {
     fn drop(&mut self) {
-        println!("ToDrop is being dropped");
+        println!("{} is being dropped", self.name);
+
+        if let Some(sink) = &self.sink {
+            sink.borrow_mut().push(format!("dropped {}", self.name));
+        }
     }
 }
 
@@ -51,6 +76,100 @@ pub fn show_raii() {
 
     // `_box2` is destroyed here, and memory gets freed
 
-    let x = ToDrop;
+    let _x = ToDrop::new("x");
     println!("Made a ToDrop!");
 }
+
+// Variables are dropped in the reverse order of their creation (LIFO),
+// and a nested scope drops all of its variables before the outer
+// scope carries on.
+fn drop_in_nested_scopes(sink: &Rc<RefCell<Vec<String>>>) {
+    let _first = ToDrop::with_sink("first", sink);
+
+    {
+        let _second = ToDrop::with_sink("second", sink);
+        let _third = ToDrop::with_sink("third", sink);
+
+        // `_third` is dropped here, then `_second`.
+    }
+
+    let _fourth = ToDrop::with_sink("fourth", sink);
+
+    // `_fourth` is dropped here, then `_first`.
+}
+
+pub fn show_drop_order() {
+    let sink = Rc::new(RefCell::new(Vec::new()));
+    drop_in_nested_scopes(&sink);
+
+    assert_eq!(*sink.borrow(), vec![
+        "dropped third",
+        "dropped second",
+        "dropped fourth",
+        "dropped first",
+    ]);
+
+    let log = Rc::new(RefCell::new(Vec::new()));
+
+    {
+        let _a = ToDrop::with_sink("a", &log);
+        let _b = ToDrop::with_sink("b", &log);
+        let _c = ToDrop::with_sink("c", &log);
+
+        // nothing is dropped while the block is running.
+        assert!(log.borrow().is_empty());
+    }
+
+    assert_eq!(*log.borrow(), vec!["dropped c", "dropped b", "dropped a"]);
+}
+
+// A classic use of RAII: timing a scope.
+// `ScopedTimer` records the time it was created and reports how long
+// it lived once it goes out of scope, no matter how the scope is left.
+// The `on_drop` callback receives the elapsed duration.
+struct ScopedTimer {
+    label: String,
+    start: Instant,
+    on_drop: Box<dyn Fn(Duration)>,
+}
+
+impl ScopedTimer {
+    fn new(label: &str, on_drop: Box<dyn Fn(Duration)>) -> ScopedTimer {
+        ScopedTimer {
+            label: String::from(label),
+            start: Instant::now(),
+            on_drop,
+        }
+    }
+}
+
+impl Drop for ScopedTimer {
+    fn drop(&mut self) {
+        let elapsed = self.start.elapsed();
+        println!("{} took {:?}", self.label, elapsed);
+
+        (self.on_drop)(elapsed);
+    }
+}
+
+pub fn show_scoped_timer() {
+    // the callback needs its own handle to where it records the duration.
+    let recorded = Rc::new(Cell::new(None));
+    let recorder = Rc::clone(&recorded);
+
+    {
+        let _timer = ScopedTimer::new("counting boxes", Box::new(move |elapsed| {
+            recorder.set(Some(elapsed));
+        }));
+
+        for _ in 0u32..1_000 {
+            create_box();
+        }
+
+        // `_timer` is dropped here and the callback fires.
+    }
+
+    let elapsed = recorded.get();
+    assert!(elapsed.is_some());
+    assert!(elapsed.unwrap() >= Duration::ZERO);
+}
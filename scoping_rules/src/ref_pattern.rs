@@ -54,4 +54,25 @@ pub fn show_ref_pattern() {
     }
 
     println!("tuple is {:?}", mutable_tuple);
-}
\ No newline at end of file
+}
+
+// Borrow the first word out of an owned `String`. The returned `&str`
+// points into `sentence`, so it can't outlive it, and nothing is copied.
+// Taking `&String` rather than `&str` keeps the owned data in view.
+#[allow(clippy::ptr_arg)]
+fn first_word_ref(sentence: &String) -> Option<&str> {
+    sentence.split_whitespace().next()
+}
+
+pub fn show_first_word_ref() {
+    let sentence = String::from("  borrowed slices point into owned data");
+    let single = String::from("ferris");
+    let blank = String::from("   ");
+
+    println!("the first word of {:?} is {:?}", sentence, first_word_ref(&sentence));
+
+    assert_eq!(first_word_ref(&sentence), Some("borrowed"));
+    assert_eq!(first_word_ref(&single), Some("ferris"));
+    assert_eq!(first_word_ref(&blank), None);
+    assert_eq!(first_word_ref(&String::new()), None);
+}
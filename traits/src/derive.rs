@@ -4,6 +4,8 @@
     Traits can be implemented for any data type
 */
 
+use std::collections::HashMap;
+
 struct Sheep { naked: bool, name: &'static str }
 
 trait Animal {
@@ -37,6 +39,20 @@ impl Sheep {
             self.naked = true;
         }
     }
+
+    // the wool grows back, so the sheep can be sheared again.
+    fn regrow(&mut self) {
+        if self.is_naked() {
+            println!("{}'s wool grows back!", self.name);
+            self.naked = false;
+        } else {
+            println!("{} already has its wool...", self.name());
+        }
+    }
+
+    fn wool_ready(&self) -> bool {
+        !self.naked
+    }
 }
 
 // Implement Animal trait for Sheep struct.
@@ -78,6 +94,16 @@ pub fn show_trait_implementation() {
     sonic.talk();
     sonic.shear();
     sonic.talk();
+
+    // shearing is no longer one way
+    assert!(sonic.is_naked());
+    assert_eq!(sonic.noise(), "baa?");
+
+    sonic.regrow();
+    sonic.talk();
+    assert!(!sonic.is_naked());
+    assert!(sonic.wool_ready());
+    assert_eq!(sonic.noise(), "baa!");
 }
 
 // derive
@@ -138,6 +164,75 @@ fn random_mammal(random_number: f64) -> Box<dyn Mammal> {
     }
 }
 
+// The same choice, but reproducible: one step of a linear congruential
+// generator turns `seed` into a pseudo-random number, the top 53 bits
+// of which make an `f64` between 0 and 1. The same seed always
+// picks the same mammal.
+fn random_mammal_seeded(seed: u64) -> Box<dyn Mammal> {
+    let next = seed
+        .wrapping_mul(6364136223846793005)
+        .wrapping_add(1442695040888963407);
+    let random_number = (next >> 11) as f64 / (1u64 << 53) as f64;
+
+    random_mammal(random_number)
+}
+
+// Takes the animals by value, so they are dropped once the
+// longest noise among them has been found.
+fn loudest(animals: Vec<Box<dyn Mammal>>) -> Option<&'static str> {
+    animals.into_iter()
+        .map(|animal| animal.noise())
+        .max_by_key(|noise| noise.len())
+}
+
+// Static dispatch: a copy of this function is compiled for every
+// `Mammal` it is called with, so `noise` is a direct call.
+fn make_noises_static<M: Mammal>(m: &M, times: usize) -> String {
+    vec![m.noise(); times].join(" ")
+}
+
+// Dynamic dispatch: a single function for every `Mammal`, `noise`
+// is looked up in the vtable behind the `&dyn` pointer at runtime.
+fn make_noises_dynamic(m: &dyn Mammal, times: usize) -> String {
+    vec![m.noise(); times].join(" ")
+}
+
+pub fn show_static_and_dynamic_dispatch() {
+    let goat = Goat {};
+
+    let static_noises = make_noises_static(&goat, 3);
+    let dynamic_noises = make_noises_dynamic(&goat, 3);
+
+    println!("static: {}, dynamic: {}", static_noises, dynamic_noises);
+    assert_eq!(static_noises, "bleeh! bleeh! bleeh!");
+    assert_eq!(static_noises, dynamic_noises);
+}
+
+// The reverse of `noise`: which farm animal makes this sound?
+fn identify(sound: &str) -> Option<&'static str> {
+    match sound {
+        "baa!" | "baa?" => Some("sheep"),
+        "moooooo!" => Some("cow"),
+        "bleeh!" => Some("goat"),
+        _ => None,
+    }
+}
+
+pub fn show_identify_animal() {
+    let dolly: Sheep = Animal::new("dolly");
+    let noises = [dolly.noise(), Cow {}.noise(), Goat {}.noise()];
+
+    for noise in noises {
+        println!("{} is the sound of a {:?}", noise, identify(noise));
+    }
+
+    assert_eq!(identify("baa!"), Some("sheep"));
+    assert_eq!(identify("baa?"), Some("sheep"));
+    assert_eq!(identify("moooooo!"), Some("cow"));
+    assert_eq!(identify("bleeh!"), Some("goat"));
+    assert_eq!(identify("meow"), None);
+}
+
 // impl Trait
 // can be used in two locations:
 // as an argument type
@@ -176,6 +271,79 @@ fn parse_csv_document(reader: impl std::io::BufRead) -> std::io::Result<Vec<Vec<
         .collect() // Collect all lines into a Vec<Vec<String>>
 }
 
+// Checks the shape of a csv document before it gets processed: every
+// row, the header included, must have exactly `expected_cols` fields.
+// Blank lines are skipped. The first row is the header, so the number
+// of data rows after it is returned when all of them match.
+fn validate_csv<R: std::io::BufRead>(reader: R, expected_cols: usize) -> std::io::Result<usize> {
+    let rows = parse_csv(reader)?;
+    let mut count = 0;
+    let mut header_seen = false;
+
+    for (i, row) in rows.iter().enumerate() {
+        if row.len() == 1 && row[0].is_empty() {
+            continue;
+        }
+
+        if row.len() != expected_cols {
+            return Err(std::io::Error::new(
+                std::io::ErrorKind::InvalidData,
+                format!("line {} has {} fields, expected {}", i + 1, row.len(), expected_cols),
+            ));
+        }
+
+        if header_seen {
+            count += 1;
+        } else {
+            header_seen = true;
+        }
+    }
+
+    Ok(count)
+}
+
+pub fn show_validate_csv() {
+    let valid = "name, age\ndolly, 3\n\nshaun, 5\n";
+    let rows = validate_csv(valid.as_bytes(), 2);
+    println!("valid csv has {:?} data rows", rows);
+    assert_eq!(rows.unwrap(), 2);
+    assert_eq!(validate_csv("name, age\n".as_bytes(), 2).unwrap(), 0);
+
+    let mismatched = "name, age\ndolly\nshaun, 5\n";
+    let error = validate_csv(mismatched.as_bytes(), 2).unwrap_err();
+    println!("mismatched csv: {}", error);
+    assert_eq!(error.kind(), std::io::ErrorKind::InvalidData);
+    assert_eq!(error.to_string(), "line 2 has 1 fields, expected 2");
+}
+
+// Another reader of any `BufRead`: counts how often every word occurs
+// over all of its lines. Words are split on whitespace and counted
+// case-insensitively, punctuation is kept as part of the word.
+fn word_frequencies<R: std::io::BufRead>(reader: R) -> std::io::Result<HashMap<String, usize>> {
+    let mut counts = HashMap::new();
+
+    for line in reader.lines() {
+        for word in line?.split_whitespace() {
+            *counts.entry(word.to_lowercase()).or_insert(0) += 1;
+        }
+    }
+
+    Ok(counts)
+}
+
+pub fn show_word_frequencies() {
+    let text = "The cow says moo\nthe goat  says bleeh\n";
+    let counts = word_frequencies(text.as_bytes()).unwrap();
+    println!("word frequencies: {:?}", counts);
+
+    assert_eq!(counts.len(), 6);
+    assert_eq!(counts["the"], 2);
+    assert_eq!(counts["says"], 2);
+    assert_eq!(counts["cow"], 1);
+    assert_eq!(counts["bleeh"], 1);
+    assert_eq!(counts.get("The"), None);
+}
+
 // As a return type
 // If your function returns a type that implements MyTrait,
 // you can write its return type as -> impl MyTrait.
@@ -217,4 +385,14 @@ pub fn show_return_trait_from_function() {
     let random_number = 0.234;
     let animal = random_mammal(random_number);
     println!("You've randomly chosen a mammal, and it says {}", animal.noise());
+
+    let seeded = random_mammal_seeded(42);
+    println!("Seed 42 chose a mammal, and it says {}", seeded.noise());
+    assert_eq!(seeded.noise(), random_mammal_seeded(42).noise());
+
+    let animals: Vec<Box<dyn Mammal>> = vec![Box::new(Goat {}), Box::new(Cow {})];
+    let noise = loudest(animals);
+    println!("The loudest mammal says {:?}", noise);
+    assert_eq!(noise, Some("moooooo!"));
+    assert_eq!(loudest(Vec::new()), None);
 }
@@ -4,6 +4,10 @@ mod iterators;
 fn main() {
     derive::show_trait_implementation();
     derive::show_return_trait_from_function();
+    derive::show_static_and_dynamic_dispatch();
+    derive::show_identify_animal();
+    derive::show_validate_csv();
+    derive::show_word_frequencies();
 
     iterators::show_iterator_trait();
 }
@@ -66,3 +66,50 @@ pub fn display_type_casting() {
         println!("nan as u8 is {}", f32::NAN.to_int_unchecked::<u8>());
     }
 }
+
+// `as` silently truncates a value that doesn't fit, `TryFrom` checks
+// first and fails instead, so an overflow can be reported.
+fn narrow_u32_to_u8(n: u32) -> Result<u8, String> {
+    u8::try_from(n).map_err(|_| format!("{} does not fit in a u8", n))
+}
+
+pub fn display_checked_narrowing() {
+    let big = 256_u32;
+    println!("{} as u8 is {}, but narrowing gives {:?}", big, big as u8, narrow_u32_to_u8(big));
+
+    assert_eq!(narrow_u32_to_u8(200), Ok(200));
+    assert!(narrow_u32_to_u8(256).is_err());
+    assert_eq!(narrow_u32_to_u8(0), Ok(0));
+}
+
+// Instead of wrapping like `as`, values out of range stick
+// to the nearest bound of `u8`.
+fn saturating_to_u8(n: i32) -> u8 {
+    n.clamp(u8::MIN as i32, u8::MAX as i32) as u8
+}
+
+pub fn display_saturating_conversion() {
+    for n in [300, -5] {
+        println!("{} as u8 wraps to {}, saturating gives {}", n, n as u8, saturating_to_u8(n));
+    }
+
+    assert_eq!(saturating_to_u8(-5), 0);
+    assert_eq!(saturating_to_u8(300), 255);
+    assert_eq!(saturating_to_u8(100), 100);
+}
+
+// Casting a signed integer to the unsigned type of the same size keeps
+// every bit as it is, so the unsigned value shows the two's complement
+// representation of the signed one. `{:08b}` pads the binary to 8 digits.
+fn two_complement_bits(n: i8) -> String {
+    format!("{:08b}", n as u8)
+}
+
+pub fn display_two_complement() {
+    println!("-1 as two's complement bits is {}", two_complement_bits(-1));
+
+    assert_eq!(two_complement_bits(0), "00000000");
+    assert_eq!(two_complement_bits(1), "00000001");
+    assert_eq!(two_complement_bits(-1), "11111111");
+    assert_eq!(two_complement_bits(i8::MIN), "10000000");
+}
@@ -26,6 +26,27 @@ pub fn display_literals() {
         std::mem::size_of_val(&f), std::mem::size_of_val(&f) * 8);
 }
 
+// `u8` literals only go up to 255, so adding a few of them can
+// already overflow. `checked_add` reports that as `None` where
+// `wrapping_add` would silently start again from 0.
+fn checked_sum(values: &[u8]) -> Option<u8> {
+    values.iter().try_fold(0u8, |total, &value| total.checked_add(value))
+}
+
+pub fn display_checked_sum() {
+    let fits = [100u8, 50, 105];
+    let overflows = [200u8, 100];
+
+    let wrapped = overflows.iter().fold(0u8, |total, &value| total.wrapping_add(value));
+    println!("checked sum of {:?} is {:?}, of {:?} is {:?} (wrapping gives {})",
+             fits, checked_sum(&fits), overflows, checked_sum(&overflows), wrapped);
+
+    assert_eq!(checked_sum(&fits), Some(255));
+    assert_eq!(checked_sum(&overflows), None);
+    assert_eq!(wrapped, 44);
+    assert_eq!(checked_sum(&[]), Some(0));
+}
+
 /*The type statement can be used to give a new name to an existing type. Types must have UpperCamelCase names, or the compiler will raise a warning
 
 This is a way to create a new type from another type, but having an
@@ -80,6 +101,47 @@ pub fn use_from_and_into() {
     println!("my Number(Into) is {:?}", number);
 }
 
+// `From` works both ways between two temperature scales,
+// each newtype wraps the degrees as an `f64`.
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Celsius(f64);
+
+#[derive(Debug, PartialEq, Clone, Copy)]
+struct Fahrenheit(f64);
+
+impl From<Celsius> for Fahrenheit {
+    fn from(celsius: Celsius) -> Self {
+        Fahrenheit(celsius.0 * 9.0 / 5.0 + 32.0)
+    }
+}
+
+impl From<Fahrenheit> for Celsius {
+    fn from(fahrenheit: Fahrenheit) -> Self {
+        Celsius((fahrenheit.0 - 32.0) * 5.0 / 9.0)
+    }
+}
+
+pub fn convert_temperatures() {
+    let freezing = Fahrenheit::from(Celsius(0.0));
+    let boiling: Fahrenheit = Celsius(100.0).into();
+    println!("water freezes at {:?} and boils at {:?}", freezing, boiling);
+
+    assert_eq!(freezing, Fahrenheit(32.0));
+    assert_eq!(boiling, Fahrenheit(212.0));
+    assert_eq!(Celsius::from(Fahrenheit(32.0)), Celsius(0.0));
+    assert_eq!(Celsius::from(Fahrenheit(212.0)), Celsius(100.0));
+
+    // Arbitrary values may pick up floating point error on the way
+    // there and back, so compare them within a small margin.
+    let body = Celsius(37.0);
+    let round_trip = Celsius::from(Fahrenheit::from(body));
+    assert!((round_trip.0 - body.0).abs() < 1e-9);
+
+    let room = Fahrenheit(68.5);
+    let round_trip = Fahrenheit::from(Celsius::from(room));
+    assert!((round_trip.0 - room.0).abs() < 1e-9);
+}
+
 /* TryFrom and TryInto are generic traits for converting between types.
 Unlike From/Into, the TryFrom/TryInto traits are used for fallible
 conversions, and as such, return Results.
@@ -121,6 +183,60 @@ pub fn display_try_from_into_conversion() {
     assert_eq!(result, Err(()));
 }
 
+// A distance always kept in meters, parsed from strings
+// like "5km", "300m" or "2mi". Parsing fails on a number that
+// can't be read or a unit that isn't known.
+#[derive(Debug, PartialEq)]
+struct Distance {
+    meters: f64,
+}
+
+impl TryFrom<&str> for Distance {
+    type Error = String;
+
+    fn try_from(value: &str) -> Result<Self, Self::Error> {
+        let value = value.trim();
+        // the unit starts at the first letter.
+        let unit_start = value.find(|c: char| c.is_alphabetic()).unwrap_or(value.len());
+        let (number, unit) = value.split_at(unit_start);
+
+        if number.trim().is_empty() {
+            return Err(format!("missing number in `{}`", value));
+        }
+
+        let amount: f64 = match number.trim().parse() {
+            Ok(amount) => amount,
+            Err(_) => return Err(format!("`{}` is not a valid number in `{}`", number, value)),
+        };
+
+        let meters_per_unit = match unit {
+            "m" => 1.0,
+            "km" => 1000.0,
+            "mi" => 1609.344,
+            _ => return Err(format!("unknown unit `{}` in `{}`", unit, value)),
+        };
+
+        Ok(Distance { meters: amount * meters_per_unit })
+    }
+}
+
+pub fn display_distance_parsing() {
+    println!("5km is {:?}", Distance::try_from("5km"));
+
+    assert_eq!(Distance::try_from("5km"), Ok(Distance { meters: 5000.0 }));
+    assert_eq!(Distance::try_from("300m"), Ok(Distance { meters: 300.0 }));
+    assert_eq!(Distance::try_from("2mi"), Ok(Distance { meters: 3218.688 }));
+
+    let unknown_unit: Result<Distance, String> = "5ft".try_into();
+    println!("5ft is {:?}", unknown_unit);
+    assert_eq!(unknown_unit, Err(String::from("unknown unit `ft` in `5ft`")));
+
+    let not_a_number = Distance::try_from("1.2.3km");
+    println!("1.2.3km is {:?}", not_a_number);
+    assert_eq!(not_a_number, Err(String::from("`1.2.3` is not a valid number in `1.2.3km`")));
+    assert_eq!(Distance::try_from("km"), Err(String::from("missing number in `km`")));
+}
+
 // To convert any type to a String is as simple as
 // implementing the ToString trait for the type.
 // Instead of implementing ToString, we should instead,
@@ -128,11 +244,43 @@ pub fn display_try_from_into_conversion() {
 // provides the ToString trait, any type that implements
 // the Display trait, also implements the ToString trait.
 
-#[derive(Debug)]
+// Deriving the comparison traits compares circles field by field,
+// and the radius is the only field they have, so circles are
+// equal and ordered by their radius. `Ord` lets a `Vec<Circle>` be sorted.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord)]
 struct Circle {
     radius: u16,
 }
 
+impl Circle {
+    // Keeps the first circle of every radius, in their original order.
+    fn dedup_by_radius(circles: Vec<Circle>) -> Vec<Circle> {
+        let mut unique: Vec<Circle> = Vec::new();
+
+        for circle in circles {
+            if !unique.contains(&circle) {
+                unique.push(circle);
+            }
+        }
+
+        unique
+    }
+
+    fn area(&self) -> f64 {
+        let radius = f64::from(self.radius);
+
+        std::f64::consts::PI * radius * radius
+    }
+
+    // The radius is a whole number, so the scaled radius is rounded.
+    // `as` saturates, a negative or too large radius becomes 0 or `u16::MAX`.
+    fn scale(&self, factor: f64) -> Circle {
+        let radius = (f64::from(self.radius) * factor).round() as u16;
+
+        Circle { radius }
+    }
+}
+
 impl fmt::Display for Circle {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
         write!(f, "Circle of radius {}", self.radius)
@@ -180,6 +328,40 @@ pub fn display_string_conversion() {
     println!("\nSum: {:?}", sum);
 }
 
+pub fn display_circle_dedup() {
+    let circles: Vec<Circle> = [5, 3, 5, 3, 8].iter()
+        .map(|&radius| Circle { radius })
+        .collect();
+
+    let unique = Circle::dedup_by_radius(circles);
+    println!("circles with distinct radius: {:?}", unique);
+
+    let radii: Vec<u16> = unique.iter().map(|circle| circle.radius).collect();
+    assert_eq!(radii, vec![5, 3, 8]);
+}
+
+pub fn display_circle_ordering() {
+    let mut circles = vec![Circle { radius: 3 }, Circle { radius: 1 }, Circle { radius: 2 }];
+    circles.sort();
+
+    println!("circles sorted by radius: {:?}", circles);
+    assert_eq!(circles, vec![Circle { radius: 1 }, Circle { radius: 2 }, Circle { radius: 3 }]);
+    let (circle, same_size) = (Circle { radius: 5 }, Circle { radius: 5 });
+    assert_eq!(circle, same_size);
+    assert!(Circle { radius: 1 } < Circle { radius: 5 });
+}
+
+pub fn display_circle_geometry() {
+    let unit = Circle { radius: 1 };
+    println!("{} has area {}", unit, unit.area());
+    assert!((unit.area() - std::f64::consts::PI).abs() < 1e-12);
+
+    let scaled = Circle { radius: 2 }.scale(3.0);
+    println!("Circle of radius 2 scaled by 3 is {}", scaled);
+    assert_eq!(scaled, Circle { radius: 6 });
+    assert_eq!(Circle { radius: 3 }.scale(0.5), Circle { radius: 2 });
+}
+
 /*
 Blocks are expressions too, so they can be used as values in assignments.
 The last expression in the block will be assigned to the place expression
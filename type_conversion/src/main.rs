@@ -1,12 +1,24 @@
 mod casting;
 mod literals;
+mod util;
 
 fn main() {
     casting::display_type_casting();
+    casting::display_two_complement();
+    casting::display_checked_narrowing();
+    casting::display_saturating_conversion();
     literals::display_literals();
+    literals::display_checked_sum();
     literals::display_aliasing();
     literals::use_from_and_into();
+    literals::convert_temperatures();
     literals::display_try_from_into_conversion();
+    literals::display_distance_parsing();
     literals::display_string_conversion();
+    literals::display_circle_dedup();
+    literals::display_circle_ordering();
+    literals::display_circle_geometry();
     literals::show_expressions();
+
+    util::display_clamp();
 }
@@ -0,0 +1,33 @@
+/*
+    Small generic helpers.
+
+    `PartialOrd` is all that's needed to compare values, so these work
+    for integers and floats alike, where `Ord::clamp` would leave the
+    floats out.
+*/
+
+// Keep `value` within `min` and `max`, both included.
+// A `min` greater than `max` is a bug in the caller, debug builds panic on it.
+pub fn clamp<T: PartialOrd>(value: T, min: T, max: T) -> T {
+    debug_assert!(min <= max, "clamp called with min greater than max");
+
+    if value < min {
+        min
+    } else if value > max {
+        max
+    } else {
+        value
+    }
+}
+
+pub fn display_clamp() {
+    println!("\nclamp 42 to 0..=10 -> {}", clamp(42, 0, 10));
+
+    assert_eq!(clamp(-5, 0, 10), 0);
+    assert_eq!(clamp(42, 0, 10), 10);
+    assert_eq!(clamp(7, 0, 10), 7);
+
+    assert_eq!(clamp(-0.5, 0.0, 1.0), 0.0);
+    assert_eq!(clamp(1.5, 0.0, 1.0), 1.0);
+    assert_eq!(clamp(0.25, 0.0, 1.0), 0.25);
+}